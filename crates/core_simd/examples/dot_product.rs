@@ -140,6 +140,13 @@ pub fn dot_prod_simd_5(a: &[f32], b: &[f32]) -> f32 {
         .reduce_sum()
 }
 
+// The `slice` module has a maintained version of all of the above: it handles the remainder with a
+// masked load, keeps four accumulators so that consecutive chunks don't wait on each other, and
+// adds the products in a documented order, so the result is the same on every target.
+pub fn dot_prod_simd_6(a: &[f32], b: &[f32]) -> f32 {
+    slice::dot::<_, LANES>(a, b)
+}
+
 fn main() {
     // Empty main to make cargo happy
 }
@@ -163,8 +170,10 @@ mod tests {
         assert_eq!(0.0, dot_prod_simd_3(&a, &b));
         assert_eq!(0.0, dot_prod_simd_4(&a, &b));
         assert_eq!(0.0, dot_prod_simd_5(&a, &b));
+        assert_eq!(0.0, dot_prod_simd_6(&a, &b));
 
         // We can handle vectors that are non-multiples of 4
         assert_eq!(1003.0, dot_prod_simd_3(&x, &y));
+        assert_eq!(1003.0, dot_prod_simd_6(&x, &y));
     }
 }
\ No newline at end of file
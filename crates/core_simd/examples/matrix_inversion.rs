@@ -4,7 +4,6 @@
 // Run this code with `cargo test --example matrix_inversion`
 #![feature(array_chunks, portable_simd)]
 use core_simd::simd::*;
-use Which::*;
 
 // Gotta define our own 4x4 matrix since Rust doesn't ship multidim arrays yet :^)
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
@@ -165,21 +164,10 @@ pub fn simd_inv4x4(m: Matrix4x4) -> Option<Matrix4x4> {
     let m_2 = f32x4::from_array(m[2]);
     let m_3 = f32x4::from_array(m[3]);
 
-    const SHUFFLE01: [Which; 4] = [First(0), First(1), Second(0), Second(1)];
-    const SHUFFLE02: [Which; 4] = [First(0), First(2), Second(0), Second(2)];
-    const SHUFFLE13: [Which; 4] = [First(1), First(3), Second(1), Second(3)];
-    const SHUFFLE23: [Which; 4] = [First(2), First(3), Second(2), Second(3)];
-
-    let tmp = simd_swizzle!(m_0, m_1, SHUFFLE01);
-    let row1 = simd_swizzle!(m_2, m_3, SHUFFLE01);
-
-    let row0 = simd_swizzle!(tmp, row1, SHUFFLE02);
-    let row1 = simd_swizzle!(row1, tmp, SHUFFLE13);
-
-    let tmp = simd_swizzle!(m_0, m_1, SHUFFLE23);
-    let row3 = simd_swizzle!(m_2, m_3, SHUFFLE23);
-    let row2 = simd_swizzle!(tmp, row3, SHUFFLE02);
-    let row3 = simd_swizzle!(row3, tmp, SHUFFLE13);
+    // The algorithm works on the columns, with the halves of the odd columns swapped
+    let [row0, row1, row2, row3] = f32x4::transpose([m_0, m_1, m_2, m_3]);
+    let row1 = row1.rotate_lanes_left::<2>();
+    let row3 = row3.rotate_lanes_left::<2>();
 
     let tmp = (row2 * row3).reverse().rotate_lanes_right::<2>();
     let minor0 = row1 * tmp;
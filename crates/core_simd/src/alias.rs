@@ -62,6 +62,8 @@ alias! {
         i8x16 16
         i8x32 32
         i8x64 64
+        i8x128 128
+        i8x256 256
     }
 
     i16 = {
@@ -72,6 +74,8 @@ alias! {
         i16x16 16
         i16x32 32
         i16x64 64
+        i16x128 128
+        i16x256 256
     }
 
     i32 = {
@@ -82,6 +86,8 @@ alias! {
         i32x16 16
         i32x32 32
         i32x64 64
+        i32x128 128
+        i32x256 256
     }
 
     i64 = {
@@ -92,6 +98,8 @@ alias! {
         i64x16 16
         i64x32 32
         i64x64 64
+        i64x128 128
+        i64x256 256
     }
 
     isize = {
@@ -102,6 +110,8 @@ alias! {
         isizex16 16
         isizex32 32
         isizex64 64
+        isizex128 128
+        isizex256 256
     }
 
     u8 = {
@@ -112,6 +122,8 @@ alias! {
         u8x16 16
         u8x32 32
         u8x64 64
+        u8x128 128
+        u8x256 256
     }
 
     u16 = {
@@ -122,6 +134,8 @@ alias! {
         u16x16 16
         u16x32 32
         u16x64 64
+        u16x128 128
+        u16x256 256
     }
 
     u32 = {
@@ -132,6 +146,8 @@ alias! {
         u32x16 16
         u32x32 32
         u32x64 64
+        u32x128 128
+        u32x256 256
     }
 
     u64 = {
@@ -142,6 +158,8 @@ alias! {
         u64x16 16
         u64x32 32
         u64x64 64
+        u64x128 128
+        u64x256 256
     }
 
     usize = {
@@ -152,6 +170,8 @@ alias! {
         usizex16 16
         usizex32 32
         usizex64 64
+        usizex128 128
+        usizex256 256
     }
 
     f32 = {
@@ -162,6 +182,8 @@ alias! {
         f32x16 16
         f32x32 32
         f32x64 64
+        f32x128 128
+        f32x256 256
     }
 
     f64 = {
@@ -172,6 +194,8 @@ alias! {
         f64x16 16
         f64x32 32
         f64x64 64
+        f64x128 128
+        f64x256 256
     }
 }
 
@@ -184,6 +208,8 @@ mask_alias! {
         mask8x16 16
         mask8x32 32
         mask8x64 64
+        mask8x128 128
+        mask8x256 256
     }
 
     i16 : "16-bit" = {
@@ -194,6 +220,8 @@ mask_alias! {
         mask16x16 16
         mask16x32 32
         mask16x64 64
+        mask16x128 128
+        mask16x256 256
     }
 
     i32 : "32-bit" = {
@@ -204,6 +232,8 @@ mask_alias! {
         mask32x16 16
         mask32x32 32
         mask32x64 64
+        mask32x128 128
+        mask32x256 256
     }
 
     i64 : "64-bit" = {
@@ -214,6 +244,8 @@ mask_alias! {
         mask64x16 16
         mask64x32 32
         mask64x64 64
+        mask64x128 128
+        mask64x256 256
     }
 
     isize : "pointer-sized" = {
@@ -224,5 +256,7 @@ mask_alias! {
         masksizex16 16
         masksizex32 32
         masksizex64 64
+        masksizex128 128
+        masksizex256 256
     }
 }
\ No newline at end of file
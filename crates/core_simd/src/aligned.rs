@@ -0,0 +1,178 @@
+use crate::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+use core::mem;
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Returns `true` if the vector has the same size as `[T; LANES]`, without padding lanes.
+    ///
+    /// Only such vectors may be reinterpreted as a sequence of elements.
+    pub(crate) const IS_CONTIGUOUS: bool = mem::size_of::<Self>() == mem::size_of::<[T; LANES]>();
+
+    /// Returns `true` if the start of `slice` is aligned to `Simd<T, LANES>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::f32x4;
+    /// let vector = f32x4::splat(1.);
+    /// assert!(f32x4::is_aligned(vector.as_array()));
+    /// assert!(!f32x4::is_aligned(&vector.as_array()[1..]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_aligned(slice: &[T]) -> bool {
+        slice.as_ptr().align_offset(mem::align_of::<Self>()) == 0
+    }
+
+    /// Splits a slice into a prefix, a middle of aligned SIMD vectors, and a suffix.
+    ///
+    /// The middle is a zero-copy view of the elements in the slice. The prefix and suffix
+    /// contain the remaining elements that do not fill an aligned vector.
+    ///
+    /// As with [`slice::align_to`], the middle may be shorter than the largest possible length,
+    /// and is always empty when the vector has padding lanes. Results must not depend on how
+    /// the elements are split.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{u32x4, SimdUint};
+    /// let data: Vec<u32> = (1..=100).collect();
+    /// let (prefix, middle, suffix) = u32x4::as_simd(&data);
+    /// let sum = prefix.iter().sum::<u32>()
+    ///     + middle.iter().sum::<u32x4>().reduce_sum()
+    ///     + suffix.iter().sum::<u32>();
+    /// assert_eq!(sum, 5050);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_simd(slice: &[T]) -> (&[T], &[Self], &[T]) {
+        if !Self::IS_CONTIGUOUS {
+            return (slice, &[], &[]);
+        }
+        // Safety: The vector has the same layout as `[T; LANES]`, and every bit pattern of
+        // `[T; LANES]` is valid for the vector.
+        unsafe { slice.align_to::<Self>() }
+    }
+
+    /// Splits a mutable slice into a prefix, a middle of aligned SIMD vectors, and a suffix.
+    ///
+    /// See [`Simd::as_simd`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::f32x4;
+    /// let mut data = vec![1.0f32; 99];
+    /// let (prefix, middle, suffix) = f32x4::as_simd_mut(&mut data);
+    /// prefix.iter_mut().for_each(|x| *x *= 2.);
+    /// middle.iter_mut().for_each(|x| *x *= f32x4::splat(2.));
+    /// suffix.iter_mut().for_each(|x| *x *= 2.);
+    /// assert!(data.iter().all(|x| *x == 2.));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_simd_mut(slice: &mut [T]) -> (&mut [T], &mut [Self], &mut [T]) {
+        if !Self::IS_CONTIGUOUS {
+            return (slice, &mut [], &mut []);
+        }
+        // Safety: The vector has the same layout as `[T; LANES]`, and every bit pattern of
+        // `[T; LANES]` is valid for the vector.
+        unsafe { slice.align_to_mut::<Self>() }
+    }
+
+    /// Converts an aligned slice to a SIMD vector containing `slice[..LANES]`.
+    ///
+    /// Unlike [`Simd::from_slice`], this performs an aligned load.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is less than the vector's `Simd::LANES`, or if the slice is
+    /// not aligned to `Simd<T, LANES>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::u32x4;
+    /// let source = u32x4::from_array([1, 2, 3, 4]);
+    /// let v = u32x4::from_slice_aligned(source.as_array());
+    /// assert_eq!(v.as_array(), &[1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_slice_aligned(slice: &[T]) -> Self {
+        assert!(
+            slice.len() >= LANES,
+            "slice length must be at least the number of lanes"
+        );
+        assert!(
+            Self::is_aligned(slice),
+            "slice must be aligned to the vector"
+        );
+        if !Self::IS_CONTIGUOUS {
+            // The padding lanes may be past the end of the slice.
+            return Self::from_slice(slice);
+        }
+        // Safety:
+        // - We've checked the length and alignment are sufficient.
+        // - `T` and `Simd<T, N>` are Copy types.
+        unsafe { slice.as_ptr().cast::<Self>().read() }
+    }
+
+    /// Writes a SIMD vector to the first `LANES` elements of an aligned slice.
+    ///
+    /// This performs an aligned store.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is less than the vector's `Simd::LANES`, or if the slice is
+    /// not aligned to `Simd<T, LANES>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::u32x4;
+    /// let mut dest = [u32x4::splat(0); 2];
+    /// let v = u32x4::from_array([1, 2, 3, 4]);
+    /// v.write_to_slice_aligned(dest[1].as_mut_array());
+    /// assert_eq!(dest[1], v);
+    /// ```
+    #[inline]
+    pub fn write_to_slice_aligned(self, slice: &mut [T]) {
+        assert!(
+            slice.len() >= LANES,
+            "slice length must be at least the number of lanes"
+        );
+        assert!(
+            Self::is_aligned(slice),
+            "slice must be aligned to the vector"
+        );
+        if !Self::IS_CONTIGUOUS {
+            // The padding lanes may be past the end of the slice.
+            slice[..LANES].copy_from_slice(self.as_array());
+            return;
+        }
+        // Safety:
+        // - We've checked the length and alignment are sufficient.
+        // - `T` and `Simd<T, N>` are Copy types.
+        unsafe { slice.as_mut_ptr().cast::<Self>().write(self) }
+    }
+}
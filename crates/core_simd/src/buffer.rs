@@ -0,0 +1,179 @@
+use crate::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+
+/// An owned buffer of elements that is aligned to `Simd<T, LANES>`.
+///
+/// The buffer dereferences to a slice of its elements, and can also be viewed as a slice of
+/// SIMD vectors without copying. The storage is padded to a whole number of vectors.
+///
+/// # Panics
+///
+/// The constructors panic if `Simd<T, LANES>` has padding lanes, which is the case for some
+/// lane counts that are not a power of two.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{f32x4, SimdBuffer};
+/// let mut buffer = SimdBuffer::<f32, 4>::from_slice(&[1., 2., 3., 4., 5., 6.]);
+/// let (vectors, remainder) = buffer.as_simd_mut();
+/// vectors.iter_mut().for_each(|v| *v *= f32x4::splat(10.));
+/// remainder.iter_mut().for_each(|x| *x *= 10.);
+/// assert_eq!(&buffer[..], &[10., 20., 30., 40., 50., 60.]);
+/// ```
+#[derive(Clone)]
+pub struct SimdBuffer<T, const LANES: usize>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    vectors: Vec<Simd<T, LANES>>,
+    len: usize,
+}
+
+impl<T, const LANES: usize> SimdBuffer<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Creates an empty buffer.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_vectors(Vec::new(), 0)
+    }
+
+    /// Creates a buffer of `len` elements, each equal to `value`.
+    #[inline]
+    #[must_use]
+    pub fn splat(value: T, len: usize) -> Self {
+        let vectors = alloc::vec![Simd::splat(value); (len + LANES - 1) / LANES];
+        Self::from_vectors(vectors, len)
+    }
+
+    /// Creates a buffer containing a copy of `slice`.
+    #[must_use]
+    pub fn from_slice(slice: &[T]) -> Self {
+        let mut vectors = Vec::with_capacity((slice.len() + LANES - 1) / LANES);
+        let mut chunks = slice.chunks_exact(LANES);
+        vectors.extend(chunks.by_ref().map(Simd::from_slice));
+        if let Some(&first) = chunks.remainder().first() {
+            let mut last = Simd::splat(first);
+            last.as_mut_array()[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
+            vectors.push(last);
+        }
+        Self::from_vectors(vectors, slice.len())
+    }
+
+    fn from_vectors(vectors: Vec<Simd<T, LANES>>, len: usize) -> Self {
+        assert!(
+            Simd::<T, LANES>::IS_CONTIGUOUS,
+            "vectors with padding lanes can't be viewed as a slice"
+        );
+        Self { vectors, len }
+    }
+
+    /// Returns the number of elements in the buffer.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the elements of the buffer.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        // Safety: The vectors have no padding lanes and contain at least `len` elements.
+        unsafe { core::slice::from_raw_parts(self.vectors.as_ptr().cast(), self.len) }
+    }
+
+    /// Returns the elements of the buffer as a mutable slice.
+    #[inline]
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: The vectors have no padding lanes and contain at least `len` elements.
+        unsafe { core::slice::from_raw_parts_mut(self.vectors.as_mut_ptr().cast(), self.len) }
+    }
+
+    /// Returns the complete vectors of the buffer, followed by the remaining elements.
+    #[inline]
+    #[must_use]
+    pub fn as_simd(&self) -> (&[Simd<T, LANES>], &[T]) {
+        let complete = self.len / LANES;
+        (
+            &self.vectors[..complete],
+            &self.as_slice()[complete * LANES..],
+        )
+    }
+
+    /// Returns the complete vectors of the buffer, followed by the remaining elements.
+    #[inline]
+    #[must_use]
+    pub fn as_simd_mut(&mut self) -> (&mut [Simd<T, LANES>], &mut [T]) {
+        let complete = self.len / LANES;
+        let len = self.len;
+        let (vectors, last) = self.vectors.split_at_mut(complete);
+        let remainder = match last.first_mut() {
+            Some(last) => &mut last.as_mut_array()[..len % LANES],
+            None => &mut [],
+        };
+        (vectors, remainder)
+    }
+}
+
+impl<T, const LANES: usize> Default for SimdBuffer<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const LANES: usize> Deref for SimdBuffer<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const LANES: usize> DerefMut for SimdBuffer<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const LANES: usize> core::fmt::Debug for SimdBuffer<T, LANES>
+where
+    T: SimdElement + core::fmt::Debug,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
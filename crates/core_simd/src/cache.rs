@@ -0,0 +1,154 @@
+use crate::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+use core::intrinsics;
+
+/// How long prefetched data is expected to be used, which determines which levels of the cache
+/// it is brought into.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Locality {
+    /// The data will be used once, and should disturb the cache as little as possible.
+    ///
+    /// On x86, this corresponds to `prefetchnta`.
+    None,
+    /// The data will be used for a short time, and should be kept in the outer levels of the
+    /// cache.
+    ///
+    /// On x86, this corresponds to `prefetcht2`.
+    Low,
+    /// The data will be used for a moderate time.
+    ///
+    /// On x86, this corresponds to `prefetcht1`.
+    Moderate,
+    /// The data will be used repeatedly, and should be kept in all levels of the cache.
+    ///
+    /// On x86, this corresponds to `prefetcht0`.
+    High,
+}
+
+/// Hints that the memory at `ptr` will soon be read.
+///
+/// This never dereferences `ptr`, so it is safe to call with any pointer, including dangling
+/// or null pointers. On targets without prefetch instructions, this does nothing.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{f32x8, prefetch_read, Locality};
+/// let data = vec![1.0f32; 1024];
+/// let mut sum = f32x8::splat(0.);
+/// for (i, chunk) in data.chunks_exact(8).enumerate() {
+///     prefetch_read(data.as_ptr().wrapping_add(i * 8 + 64), Locality::High);
+///     sum += f32x8::from_slice(chunk);
+/// }
+/// # assert_eq!(sum, f32x8::splat(128.));
+/// ```
+#[inline]
+pub fn prefetch_read<T>(ptr: *const T, locality: Locality) {
+    // Safety: Prefetches have no effect on the behavior of the program, even if the pointer
+    // is invalid.
+    unsafe {
+        match locality {
+            Locality::None => intrinsics::prefetch_read_data(ptr, 0),
+            Locality::Low => intrinsics::prefetch_read_data(ptr, 1),
+            Locality::Moderate => intrinsics::prefetch_read_data(ptr, 2),
+            Locality::High => intrinsics::prefetch_read_data(ptr, 3),
+        }
+    }
+}
+
+/// Hints that the memory at `ptr` will soon be written.
+///
+/// This never dereferences `ptr`, so it is safe to call with any pointer, including dangling
+/// or null pointers. On targets without prefetch instructions, this does nothing.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{u8x16, prefetch_write, Locality};
+/// let mut data = vec![0u8; 4096];
+/// for (i, chunk) in data.chunks_exact_mut(16).enumerate() {
+///     prefetch_write(chunk.as_ptr().wrapping_add(256), Locality::Moderate);
+///     chunk.copy_from_slice(u8x16::splat(i as u8).as_array());
+/// }
+/// # assert_eq!(data[4095], 255);
+/// ```
+#[inline]
+pub fn prefetch_write<T>(ptr: *const T, locality: Locality) {
+    // Safety: Prefetches have no effect on the behavior of the program, even if the pointer
+    // is invalid.
+    unsafe {
+        match locality {
+            Locality::None => intrinsics::prefetch_write_data(ptr, 0),
+            Locality::Low => intrinsics::prefetch_write_data(ptr, 1),
+            Locality::Moderate => intrinsics::prefetch_write_data(ptr, 2),
+            Locality::High => intrinsics::prefetch_write_data(ptr, 3),
+        }
+    }
+}
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Writes a SIMD vector to the first `LANES` elements of `slice`, hinting that the data
+    /// will not be read again soon and should bypass the cache.
+    ///
+    /// If `slice` is aligned to `Simd<T, LANES>`, this is a streaming store (such as `movntps`
+    /// on x86). Otherwise, it is an ordinary store.
+    ///
+    /// # Safety
+    ///
+    /// On x86, streaming stores are not ordered with other memory accesses. After calling this,
+    /// and before the written memory is accessed in any other way, including by the calling
+    /// thread, the calling thread must issue a store fence such as `_mm_sfence`. In particular,
+    /// functions that call this should generally issue the fence before they return.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is less than the vector's `Simd::LANES`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::u32x4;
+    /// let mut dest = vec![0u32; 1 << 12];
+    /// let (prefix, middle, suffix) = u32x4::as_simd_mut(&mut dest);
+    /// prefix.fill(7);
+    /// for vector in middle {
+    ///     // Safety: the store fence is issued below, before `dest` is read.
+    ///     unsafe { u32x4::splat(7).store_nontemporal(vector.as_mut_array()) };
+    /// }
+    /// #[cfg(all(target_arch = "x86", target_feature = "sse"))]
+    /// // Safety: SSE is enabled.
+    /// unsafe { core::arch::x86::_mm_sfence() };
+    /// #[cfg(target_arch = "x86_64")]
+    /// // Safety: SSE is always available on x86-64.
+    /// unsafe { core::arch::x86_64::_mm_sfence() };
+    /// suffix.fill(7);
+    /// assert!(dest.iter().all(|&x| x == 7));
+    /// ```
+    #[inline]
+    pub unsafe fn store_nontemporal(self, slice: &mut [T]) {
+        assert!(
+            slice.len() >= LANES,
+            "slice length must be at least the number of lanes"
+        );
+        if !Self::IS_CONTIGUOUS || !Self::is_aligned(slice) {
+            slice[..LANES].copy_from_slice(self.as_array());
+            return;
+        }
+        // Safety:
+        // - We've checked the length and alignment are sufficient.
+        // - `T` and `Simd<T, N>` are Copy types.
+        unsafe { intrinsics::nontemporal_store(slice.as_mut_ptr().cast::<Self>(), self) }
+    }
+}
@@ -0,0 +1,114 @@
+//! Target lowerings for carry-less multiplication.
+//!
+//! There is no generic intrinsic for carry-less multiplication, so each target's polynomial
+//! multiply instruction is called directly where it is available. Each function returns `None`
+//! if the target has no such instruction, and the caller falls back to shifts and xors.
+use crate::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+
+#[cfg(all(target_arch = "x86", target_feature = "pclmulqdq"))]
+use core::arch::x86::_mm_clmulepi64_si128;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "pclmulqdq"))]
+use core::arch::x86_64::_mm_clmulepi64_si128;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "aes"))]
+use core::arch::aarch64::vmull_p64;
+
+/// Returns the low and high halves of each lane's carry-less product, with the target's 64-bit
+/// polynomial multiply instruction.
+#[allow(unreachable_code, unused_variables)]
+#[inline]
+pub(crate) fn native_u64<const LANES: usize>(
+    a: Simd<u64, LANES>,
+    b: Simd<u64, LANES>,
+) -> Option<(Simd<u64, LANES>, Simd<u64, LANES>)>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "pclmulqdq"
+    ))]
+    {
+        // `pclmulqdq` multiplies one lane of each of two `u64x2`s, so multiply lanes in pairs
+        let (a, b) = (a.to_array(), b.to_array());
+        let mut low = [0; LANES];
+        let mut high = [0; LANES];
+        for even in (0..LANES).step_by(2) {
+            let odd = (even + 1).min(LANES - 1);
+            let x = Simd::<u64, 2>::from_array([a[even], a[odd]]).into();
+            let y = Simd::<u64, 2>::from_array([b[even], b[odd]]).into();
+            // Safety: the target feature is enabled
+            let (even_product, odd_product): (Simd<u64, 2>, Simd<u64, 2>) = unsafe {
+                (
+                    _mm_clmulepi64_si128(x, y, 0x00).into(),
+                    _mm_clmulepi64_si128(x, y, 0x11).into(),
+                )
+            };
+            low[even] = even_product[0];
+            high[even] = even_product[1];
+            low[odd] = odd_product[0];
+            high[odd] = odd_product[1];
+        }
+        return Some((Simd::from_array(low), Simd::from_array(high)));
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "aes"))]
+    {
+        let (a, b) = (a.to_array(), b.to_array());
+        let mut low = [0; LANES];
+        let mut high = [0; LANES];
+        for lane in 0..LANES {
+            // Safety: the target feature is enabled
+            let product = unsafe { vmull_p64(a[lane], b[lane]) };
+            low[lane] = product as u64;
+            high[lane] = (product >> 64) as u64;
+        }
+        return Some((Simd::from_array(low), Simd::from_array(high)));
+    }
+
+    None
+}
+
+/// Returns the low and high halves of each lane's carry-less product, by widening to 64 bits.
+#[inline]
+pub(crate) fn native_u32<const LANES: usize>(
+    a: Simd<u32, LANES>,
+    b: Simd<u32, LANES>,
+) -> Option<(Simd<u32, LANES>, Simd<u32, LANES>)>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // The 63-bit product is entirely in the low half
+    native_u64(a.cast(), b.cast())
+        .map(|(product, _)| (product.cast(), (product >> Simd::splat(32)).cast()))
+}
+
+/// Returns the low and high halves of each lane's carry-less product, as either `u32` or `u64`.
+#[inline]
+pub(crate) fn native_usize<const LANES: usize>(
+    a: Simd<usize, LANES>,
+    b: Simd<usize, LANES>,
+) -> Option<(Simd<usize, LANES>, Simd<usize, LANES>)>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    if usize::BITS == 64 {
+        native_u64(a.cast(), b.cast()).map(|(low, high)| (low.cast(), high.cast()))
+    } else {
+        native_u32(a.cast(), b.cast()).map(|(low, high)| (low.cast(), high.cast()))
+    }
+}
+
+/// Narrower lanes have no polynomial multiply instruction to use.
+#[inline]
+pub(crate) fn none<T, const LANES: usize>(
+    _: Simd<T, LANES>,
+    _: Simd<T, LANES>,
+) -> Option<(Simd<T, LANES>, Simd<T, LANES>)>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    None
+}
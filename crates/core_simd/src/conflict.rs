@@ -0,0 +1,181 @@
+use crate::simd::{
+    LaneCount, Mask, Simd, SimdElement, SimdIndex, SimdPartialEq, SimdPartialOrd,
+    SupportedLaneCount,
+};
+use core::ops::{Add, BitOr};
+
+impl<I, const LANES: usize> Simd<I, LANES>
+where
+    I: SimdIndex,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Detects indices that are repeated within a vector of indices.
+    ///
+    /// Each lane of the returned mask is `true` if the index in that lane is equal to the index
+    /// in any earlier lane. Scattering only the lanes that are `false` never writes to the same
+    /// element twice.
+    ///
+    /// This is modeled on the AVX-512 `vpconflict` instructions, but reports only whether a
+    /// conflict exists rather than which lanes conflict. Every pair of lanes is compared, so the
+    /// cost grows with the square of the number of lanes.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let idxs = Simd::from_array([3u32, 1, 3, 0, 1, 3, 2, 7]);
+    /// let conflicts = idxs.conflict_mask();
+    /// assert_eq!(
+    ///     conflicts.to_array(),
+    ///     [false, false, true, false, true, true, false, false]
+    /// );
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn conflict_mask(self) -> Mask<isize, LANES> {
+        conflicts(self.cast::<usize>(), Mask::splat(true))
+    }
+}
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Adds the values in a SIMD vector to potentially discontiguous indices in `slice`.
+    /// Unlike [`Simd::scatter`], lanes that share an index are all accumulated into the element.
+    /// Out-of-bounds indices, including negative indices, are not written.
+    ///
+    /// The additions are performed as by the `+` operator.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{u32x8, Simd};
+    /// let mut histogram = [0u32; 4];
+    /// let bins = Simd::from_array([0u32, 3, 3, 1, 0, 3, 9, 2]);
+    /// u32x8::splat(1).scatter_add(&mut histogram, bins); // Note the out-of-bounds lane.
+    /// assert_eq!(histogram, [2, 1, 1, 3]);
+    /// ```
+    #[inline]
+    pub fn scatter_add<I>(self, slice: &mut [T], idxs: Simd<I, LANES>)
+    where
+        I: SimdIndex,
+        Self: Add<Output = Self>,
+    {
+        self.scatter_combine(slice, idxs, |current, new| current + new)
+    }
+
+    /// Writes the maximum of the values in a SIMD vector and the existing elements to potentially
+    /// discontiguous indices in `slice`.
+    /// Unlike [`Simd::scatter`], lanes that share an index are all compared with the element.
+    /// Out-of-bounds indices, including negative indices, are not written.
+    ///
+    /// For floating-point elements, NaN is ignored if the other value is a number.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let mut maxima = [5, 5, 5];
+    /// let idxs = Simd::from_array([1u32, 0, 1, 2]);
+    /// Simd::from_array([7, 2, 9, -1]).scatter_max(&mut maxima, idxs);
+    /// assert_eq!(maxima, [5, 9, 5]);
+    /// ```
+    #[inline]
+    pub fn scatter_max<I>(self, slice: &mut [T], idxs: Simd<I, LANES>)
+    where
+        I: SimdIndex,
+        Self: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+    {
+        self.scatter_combine(slice, idxs, |current, new| {
+            (current.simd_lt(new) | current.simd_ne(current)).select(new, current)
+        })
+    }
+
+    /// Bitwise "or"s the values in a SIMD vector into potentially discontiguous indices in
+    /// `slice`.
+    /// Unlike [`Simd::scatter`], lanes that share an index are all combined into the element.
+    /// Out-of-bounds indices, including negative indices, are not written.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let mut flags = [0u8; 2];
+    /// let idxs = Simd::from_array([0u32, 1, 0, 0]);
+    /// Simd::from_array([0b0001, 0b0010, 0b0100, 0b1000]).scatter_or(&mut flags, idxs);
+    /// assert_eq!(flags, [0b1101, 0b0010]);
+    /// ```
+    #[inline]
+    pub fn scatter_or<I>(self, slice: &mut [T], idxs: Simd<I, LANES>)
+    where
+        I: SimdIndex,
+        Self: BitOr<Output = Self>,
+    {
+        self.scatter_combine(slice, idxs, |current, new| current | new)
+    }
+
+    /// Combines the values in a SIMD vector with the elements of `slice` at `idxs`, one set of
+    /// non-conflicting lanes at a time.
+    ///
+    /// Each pass compares every pair of lanes, and there is a pass for each lane that shares the
+    /// most common index.
+    #[inline]
+    fn scatter_combine<I>(
+        self,
+        slice: &mut [T],
+        idxs: Simd<I, LANES>,
+        combine: impl Fn(Self, Self) -> Self,
+    ) where
+        I: SimdIndex,
+    {
+        let mut remaining = I::in_bounds(idxs, slice.len());
+        // Casting the in-bounds indices to `usize` keeps distinct indices distinct.
+        let wide_idxs = idxs.cast::<usize>();
+        while remaining.any() {
+            // Each pass handles the first remaining lane for every distinct index.
+            let enable = remaining & !conflicts(wide_idxs, remaining);
+            // Safety: We have masked-off out-of-bounds lanes, and the enabled indices are unique.
+            unsafe {
+                let current = Self::gather_select_unchecked(slice, enable, idxs, self);
+                combine(current, self).scatter_select_unchecked(slice, enable, idxs);
+            }
+            remaining &= !enable;
+        }
+    }
+}
+
+/// Returns the `enable`d lanes whose index is equal to that of an earlier `enable`d lane.
+///
+/// Each `enable`d lane is compared against every lane, which takes `LANES` vector comparisons.
+#[inline]
+fn conflicts<const LANES: usize>(
+    idxs: Simd<usize, LANES>,
+    enable: Mask<isize, LANES>,
+) -> Mask<isize, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut lanes = [0; LANES];
+    for (lane, value) in lanes.iter_mut().enumerate() {
+        *value = lane;
+    }
+    let lanes = Simd::from_array(lanes);
+
+    let mut conflicts = Mask::splat(false);
+    for (lane, idx) in idxs.to_array().into_iter().enumerate() {
+        if enable.test(lane) {
+            conflicts |= idxs.simd_eq(Simd::splat(idx)) & lanes.simd_gt(Simd::splat(lane));
+        }
+    }
+    conflicts & enable
+}
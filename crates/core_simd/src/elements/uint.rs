@@ -1,6 +1,6 @@
 
 use super::sealed::Sealed;
-use crate::simd::{intrinsics, LaneCount, Simd, SupportedLaneCount};
+use crate::simd::{clmul, intrinsics, LaneCount, Simd, SupportedLaneCount};
 
 /// Operations on SIMD vectors of unsigned integers.
 pub trait SimdUint: Copy + Sealed {
@@ -62,10 +62,34 @@ pub trait SimdUint: Copy + Sealed {
 
     /// Returns the cumulative bitwise "xor" across the lanes of the vector.
     fn reduce_xor(self) -> Self::Scalar;
+
+    /// Lanewise carry-less multiply, returning the low and high halves of each product.
+    ///
+    /// Each lane is multiplied as a polynomial over GF(2), where the bits are the coefficients,
+    /// so partial products are combined with xor instead of addition. This is the multiplication
+    /// used by CRCs, GHASH and GF(2^n) arithmetic.
+    ///
+    /// `u64` lanes, and `u32` lanes, are multiplied with `pclmulqdq` on x86 targets with the
+    /// `pclmulqdq` feature, and with `pmull` on AArch64 targets with the `aes` feature. Other
+    /// targets and lane types are multiplied with shifts and xors.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// // (x + 1) * (x + 1) = x^2 + 1
+    /// let x = Simd::<u64, 2>::from_array([0b11, 1 << 63]);
+    /// let (low, high) = x.carryless_mul(Simd::from_array([0b11, 0b110]));
+    /// assert_eq!(low.to_array(), [0b101, 0]);
+    /// assert_eq!(high.to_array(), [0, 0b11]);
+    /// ```
+    fn carryless_mul(self, second: Self) -> (Self, Self);
 }
 
 macro_rules! impl_trait {
-    { $($ty:ty),* } => {
+    { $($ty:ty => $native:path),* } => {
         $(
         impl<const LANES: usize> Sealed for Simd<$ty, LANES>
         where
@@ -132,9 +156,34 @@ macro_rules! impl_trait {
                 // Safety: `self` is an integer vector
                 unsafe { intrinsics::simd_reduce_xor(self) }
             }
+
+            #[inline]
+            fn carryless_mul(self, second: Self) -> (Self, Self) {
+                if let Some(product) = $native(self, second) {
+                    return product;
+                }
+                let mut low = Self::splat(0);
+                let mut high = Self::splat(0);
+                for i in 0..<$ty>::BITS as $ty {
+                    // All ones in lanes where bit `i` of `second` is set
+                    let bit = second >> Self::splat(i) & Self::splat(1);
+                    let selected = self & (Self::splat(0) - bit);
+                    low ^= selected << Self::splat(i);
+                    if i > 0 {
+                        high ^= selected >> Self::splat(<$ty>::BITS as $ty - i);
+                    }
+                }
+                (low, high)
+            }
         }
         )*
     }
 }
 
-impl_trait! { u8, u16, u32, u64, usize }
\ No newline at end of file
+impl_trait! {
+    u8 => clmul::none,
+    u16 => clmul::none,
+    u32 => clmul::native_u32,
+    u64 => clmul::native_u64,
+    usize => clmul::native_usize
+}
\ No newline at end of file
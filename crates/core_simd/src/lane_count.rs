@@ -34,7 +34,7 @@ macro_rules! supported_lane_count {
     };
 }
 
-supported_lane_count!(1, 2, 4, 8, 16, 32, 64);
+supported_lane_count!(1, 2, 4, 8, 16, 32, 64, 128, 256);
 #[cfg(feature = "all_lane_counts")]
 supported_lane_count!(
     3, 5, 6, 7, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30,
@@ -1,8 +1,11 @@
 
 #![no_std]
 #![feature(
+    const_eval_select,
+    const_fn_floating_point_arithmetic,
     const_ptr_read,
     convert_float_to_int,
+    core_intrinsics,
     decl_macro,
     intra_doc_pointers,
     platform_intrinsics,
@@ -20,6 +23,9 @@
 #![unstable(feature = "portable_simd", issue = "86656")]
 //! Portable SIMD module.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[path = "mod.rs"]
 mod core_simd;
 pub use self::core_simd::simd;
\ No newline at end of file
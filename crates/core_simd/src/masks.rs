@@ -3,12 +3,20 @@
 //! Types representing
 #![allow(non_camel_case_types)]
 
+// The `force_bitmask` feature selects the bitmask representation on any target, so that it can
+// be tested without AVX-512 hardware.
 #[cfg_attr(
-    not(all(target_arch = "x86_64", target_feature = "avx512f")),
+    not(any(
+        feature = "force_bitmask",
+        all(target_arch = "x86_64", target_feature = "avx512f")
+    )),
     path = "masks/full_masks.rs"
 )]
 #[cfg_attr(
-    all(target_arch = "x86_64", target_feature = "avx512f"),
+    any(
+        feature = "force_bitmask",
+        all(target_arch = "x86_64", target_feature = "avx512f")
+    ),
     path = "masks/bitmask.rs"
 )]
 mod mask_impl;
@@ -19,7 +27,9 @@ pub use to_bitmask::ToBitMask;
 #[cfg(feature = "generic_const_exprs")]
 pub use to_bitmask::{bitmask_len, ToBitMaskArray};
 
-use crate::simd::{intrinsics, LaneCount, Simd, SimdElement, SimdPartialEq, SupportedLaneCount};
+use crate::simd::{
+    intrinsics, LaneCount, Simd, SimdElement, SimdPartialEq, SupportedLaneCount, Swizzle, Swizzle2,
+};
 use core::cmp::Ordering;
 use core::{fmt, mem};
 
@@ -120,24 +130,47 @@ where
 {
     /// Construct a mask by setting all lanes to the given value.
     #[inline]
-    pub fn splat(value: bool) -> Self {
+    pub const fn splat(value: bool) -> Self {
         Self(mask_impl::Mask::splat(value))
     }
 
     /// Converts an array of bools to a SIMD mask.
     #[inline]
-    pub fn from_array(array: [bool; LANES]) -> Self {
-        // SAFETY: Rust's bool has a layout of 1 byte (u8) with a value of
-        //     true:    0b_0000_0001
-        //     false:   0b_0000_0000
-        // Thus, an array of bools is also a valid array of bytes: [u8; N]
-        // This would be hypothetically valid as an "in-place" transmute,
-        // but these are "dependently-sized" types, so copy elision it is!
+    pub const fn from_array(array: [bool; LANES]) -> Self {
+        const fn from_array_const<T, const LANES: usize>(array: [bool; LANES]) -> Mask<T, LANES>
+        where
+            T: MaskElement,
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            Mask(mask_impl::Mask::from_array(array))
+        }
+
+        fn from_array_rt<T, const LANES: usize>(array: [bool; LANES]) -> Mask<T, LANES>
+        where
+            T: MaskElement,
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            // SAFETY: Rust's bool has a layout of 1 byte (u8) with a value of
+            //     true:    0b_0000_0001
+            //     false:   0b_0000_0000
+            // Thus, an array of bools is also a valid array of bytes: [u8; N]
+            // This would be hypothetically valid as an "in-place" transmute,
+            // but these are "dependently-sized" types, so copy elision it is!
+            unsafe {
+                let bytes: [u8; LANES] = mem::transmute_copy(&array);
+                let bools: Simd<i8, LANES> =
+                    intrinsics::simd_ne(Simd::from_array(bytes), Simd::splat(0u8));
+                Mask::from_int_unchecked(intrinsics::simd_cast(bools))
+            }
+        }
+
+        // Safety: both functions set the same lanes
         unsafe {
-            let bytes: [u8; LANES] = mem::transmute_copy(&array);
-            let bools: Simd<i8, LANES> =
-                intrinsics::simd_ne(Simd::from_array(bytes), Simd::splat(0u8));
-            Mask::from_int_unchecked(intrinsics::simd_cast(bools))
+            core::intrinsics::const_eval_select(
+                (array,),
+                from_array_const::<T, LANES>,
+                from_array_rt::<T, LANES>,
+            )
         }
     }
 
@@ -262,6 +295,29 @@ where
     pub fn all(self) -> bool {
         self.0.all()
     }
+
+    /// Swizzles the lanes of the mask with `S`.
+    #[inline]
+    pub(crate) fn swizzle_impl<S, const OUTPUT_LANES: usize>(self) -> Mask<T, OUTPUT_LANES>
+    where
+        S: Swizzle<LANES, OUTPUT_LANES>,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        Mask(self.0.swizzle::<S, OUTPUT_LANES>())
+    }
+
+    /// Swizzles the lanes of `self` and `other` with `S`.
+    #[inline]
+    pub(crate) fn swizzle2_impl<S, const OUTPUT_LANES: usize>(
+        self,
+        other: Self,
+    ) -> Mask<T, OUTPUT_LANES>
+    where
+        S: Swizzle2<LANES, OUTPUT_LANES>,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        Mask(self.0.swizzle2::<S, OUTPUT_LANES>(other.0))
+    }
 }
 
 // vector/array conversion
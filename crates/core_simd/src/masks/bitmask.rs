@@ -2,9 +2,61 @@
 #![allow(unused_imports)]
 use super::MaskElement;
 use crate::simd::intrinsics;
-use crate::simd::{LaneCount, Simd, SupportedLaneCount, ToBitMask};
+use crate::simd::{
+    LaneCount, Simd, SupportedLaneCount, Swizzle, Swizzle2, Swizzle2Impl, SwizzleImpl, ToBitMask,
+};
 use core::marker::PhantomData;
 
+/// The largest bitmask, in bytes, of any supported lane count.
+const MAX_BITMASK_LEN: usize = 32;
+
+/// Bitmask types that can be converted to and from the bytes of a bitmask.
+pub(crate) trait BitMaskBytes: Copy {
+    /// Creates a bitmask from its least significant bytes, with the remaining bytes zeroed.
+    fn from_bytes(bytes: &[u8]) -> Self;
+
+    /// Writes the least significant bytes of the bitmask.
+    fn to_bytes(self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_bitmask_bytes {
+    { $($int:ty),* } => {
+        $(
+        impl BitMaskBytes for $int {
+            #[inline]
+            fn from_bytes(bytes: &[u8]) -> Self {
+                let mut le_bytes = [0; core::mem::size_of::<$int>()];
+                le_bytes[..bytes.len()].copy_from_slice(bytes);
+                <$int>::from_le_bytes(le_bytes)
+            }
+
+            #[inline]
+            fn to_bytes(self, bytes: &mut [u8]) {
+                let len = bytes.len();
+                bytes.copy_from_slice(&self.to_le_bytes()[..len]);
+            }
+        }
+        )*
+    }
+}
+
+impl_bitmask_bytes! { u8, u16, u32, u64, u128 }
+
+impl<const N: usize> BitMaskBytes for [u8; N] {
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let mut array = [0; N];
+        array[..bytes.len()].copy_from_slice(bytes);
+        array
+    }
+
+    #[inline]
+    fn to_bytes(self, bytes: &mut [u8]) {
+        let len = bytes.len();
+        bytes.copy_from_slice(&self[..len]);
+    }
+}
+
 /// A mask where each lane is represented by a single bit.
 #[repr(transparent)]
 pub struct Mask<T, const LANES: usize>(
@@ -80,17 +132,30 @@ where
 {
     #[inline]
     #[must_use = "method returns a new mask and does not mutate the original value"]
-    pub fn splat(value: bool) -> Self {
-        let mut mask = <LaneCount<LANES> as SupportedLaneCount>::BitMask::default();
-        if value {
-            mask.as_mut().fill(u8::MAX)
-        } else {
-            mask.as_mut().fill(u8::MIN)
+    pub const fn splat(value: bool) -> Self {
+        Self::from_array([value; LANES])
+    }
+
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub const fn from_array(array: [bool; LANES]) -> Self {
+        // The bitmask type can't be indexed in const contexts, so build the bytes separately.
+        let mut bytes = [0u8; MAX_BITMASK_LEN];
+        let mut i = 0;
+        while i < LANES {
+            bytes[i / 8] |= (array[i] as u8) << (i % 8);
+            i += 1;
         }
-        if LANES % 8 > 0 {
-            *mask.as_mut().last_mut().unwrap() &= u8::MAX >> (8 - LANES % 8);
+
+        union Transmute<T: Copy> {
+            bytes: [u8; MAX_BITMASK_LEN],
+            bitmask: T,
         }
-        Self(mask, PhantomData)
+        assert!(LaneCount::<LANES>::BITMASK_LEN <= MAX_BITMASK_LEN);
+
+        // Safety: the bitmask is an array of `BITMASK_LEN` bytes, all of which were initialized
+        let bitmask = unsafe { Transmute { bytes }.bitmask };
+        Self(bitmask, PhantomData)
     }
 
     #[inline]
@@ -141,21 +206,77 @@ where
     }
 
     #[inline]
-    pub fn to_bitmask_integer<U>(self) -> U
+    pub fn to_bitmask_integer<U: BitMaskBytes>(self) -> U
     where
         super::Mask<T, LANES>: ToBitMask<BitMask = U>,
     {
-        // Safety: these are the same types
-        unsafe { core::mem::transmute_copy(&self.0) }
+        // The bitmask may be wider than the mask, e.g. 24 lanes are stored in 3 bytes but
+        // converted to a `u32`.
+        U::from_bytes(self.0.as_ref())
     }
 
     #[inline]
-    pub fn from_bitmask_integer<U>(bitmask: U) -> Self
+    pub fn from_bitmask_integer<U: BitMaskBytes>(bitmask: U) -> Self
     where
         super::Mask<T, LANES>: ToBitMask<BitMask = U>,
     {
-        // Safety: these are the same types
-        unsafe { Self(core::mem::transmute_copy(&bitmask), PhantomData) }
+        let mut mask = <LaneCount<LANES> as SupportedLaneCount>::BitMask::default();
+        bitmask.to_bytes(mask.as_mut());
+
+        // Clear the bits beyond the last lane
+        if LANES % 8 != 0 {
+            *mask.as_mut().last_mut().unwrap() &= u8::MAX >> (8 - LANES % 8);
+        }
+        Self(mask, PhantomData)
+    }
+
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original inputs"]
+    pub(crate) fn swizzle<S, const OUTPUT_LANES: usize>(self) -> Mask<T, OUTPUT_LANES>
+    where
+        S: Swizzle<LANES, OUTPUT_LANES>,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        // Moving bits is cheaper than expanding the bitmask to a vector, shuffling it, and
+        // packing it again.
+        let mut output = Mask::splat(false);
+        for (i, index) in <S as SwizzleImpl<LANES, OUTPUT_LANES>>::INDEX_IMPL
+            .into_iter()
+            .enumerate()
+        {
+            // Safety: `i` is less than `OUTPUT_LANES`, and `INDEX_IMPL` is checked to only
+            // contain lanes less than `LANES`
+            unsafe { output.set_unchecked(i, self.test_unchecked(index as usize)) }
+        }
+        output
+    }
+
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original inputs"]
+    pub(crate) fn swizzle2<S, const OUTPUT_LANES: usize>(self, other: Self) -> Mask<T, OUTPUT_LANES>
+    where
+        S: Swizzle2<LANES, OUTPUT_LANES>,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        let mut output = Mask::splat(false);
+        for (i, index) in <S as Swizzle2Impl<LANES, OUTPUT_LANES>>::INDEX_IMPL
+            .into_iter()
+            .enumerate()
+        {
+            // lanes are indexed by the first mask, then second mask
+            let index = index as usize;
+            // Safety: `i` is less than `OUTPUT_LANES`, and `INDEX_IMPL` is checked to only
+            // contain lanes less than `2 * LANES`
+            unsafe {
+                let value = if index < LANES {
+                    self.test_unchecked(index)
+                } else {
+                    other.test_unchecked(index - LANES)
+                };
+                output.set_unchecked(i, value);
+            }
+        }
+        output
     }
 
     #[inline]
@@ -3,7 +3,7 @@
 
 use super::MaskElement;
 use crate::simd::intrinsics;
-use crate::simd::{LaneCount, Simd, SupportedLaneCount, ToBitMask};
+use crate::simd::{LaneCount, Simd, SupportedLaneCount, Swizzle, Swizzle2, ToBitMask};
 
 #[cfg(feature = "generic_const_exprs")]
 use crate::simd::ToBitMaskArray;
@@ -78,6 +78,9 @@ pub(crate) trait ReverseBits {
     // Reverse the least significant `n` bits of `self`.
     // (Remaining bits must be 0.)
     fn reverse_bits(self, n: usize) -> Self;
+
+    // Test bit `n` of `self`, counting from the least significant bit.
+    fn test_bit(&self, n: usize) -> bool;
 }
 
 macro_rules! impl_reverse_bits {
@@ -95,12 +98,35 @@ macro_rules! impl_reverse_bits {
                     rev
                 }
             }
+
+            #[inline(always)]
+            fn test_bit(&self, n: usize) -> bool {
+                (*self >> n) & 1 == 1
+            }
         }
         )*
     }
 }
 
-impl_reverse_bits! { u8, u16, u32, u64 }
+impl_reverse_bits! { u8, u16, u32, u64, u128 }
+
+impl<const N: usize> ReverseBits for [u8; N] {
+    #[inline(always)]
+    fn reverse_bits(mut self, n: usize) -> Self {
+        // Byte array bitmasks always fill every byte, and their byte order doesn't depend on
+        // endianness, so only the bits within each byte are reversed.
+        debug_assert_eq!(n, N * 8);
+        for x in self.iter_mut() {
+            *x = x.reverse_bits();
+        }
+        self
+    }
+
+    #[inline(always)]
+    fn test_bit(&self, n: usize) -> bool {
+        (self[n / 8] >> (n % 8)) & 1 == 1
+    }
+}
 
 impl<T, const LANES: usize> Mask<T, LANES>
 where
@@ -109,10 +135,24 @@ where
 {
     #[inline]
     #[must_use = "method returns a new mask and does not mutate the original value"]
-    pub fn splat(value: bool) -> Self {
+    pub const fn splat(value: bool) -> Self {
         Self(Simd::splat(if value { T::TRUE } else { T::FALSE }))
     }
 
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub const fn from_array(array: [bool; LANES]) -> Self {
+        let mut values = [T::FALSE; LANES];
+        let mut i = 0;
+        while i < LANES {
+            if array[i] {
+                values[i] = T::TRUE;
+            }
+            i += 1;
+        }
+        Self(Simd::from_array(values))
+    }
+
     #[inline]
     #[must_use = "method returns a new bool and does not mutate the original value"]
     pub unsafe fn test_unchecked(&self, lane: usize) -> bool {
@@ -208,6 +248,28 @@ where
         }
     }
 
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original inputs"]
+    pub(crate) fn swizzle<S, const OUTPUT_LANES: usize>(self) -> Mask<T, OUTPUT_LANES>
+    where
+        S: Swizzle<LANES, OUTPUT_LANES>,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        // Every lane of the result is a lane of a valid mask
+        Mask(S::swizzle(self.0))
+    }
+
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original inputs"]
+    pub(crate) fn swizzle2<S, const OUTPUT_LANES: usize>(self, other: Self) -> Mask<T, OUTPUT_LANES>
+    where
+        S: Swizzle2<LANES, OUTPUT_LANES>,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        // Every lane of the result is a lane of a valid mask
+        Mask(S::swizzle2(self.0, other.0))
+    }
+
     #[inline]
     pub(crate) fn to_bitmask_integer<U: ReverseBits>(self) -> U
     where
@@ -229,6 +291,12 @@ where
     where
         super::Mask<T, LANES>: ToBitMask<BitMask = U>,
     {
+        // LLVM doesn't reliably lower bitmask selects with lane counts that aren't a power of
+        // two, so set each lane from its bit instead
+        if !LANES.is_power_of_two() {
+            return Self::from_array(core::array::from_fn(|i| bitmask.test_bit(i)));
+        }
+
         // LLVM assumes bit order should match endianness
         let bitmask = if cfg!(target_endian = "big") {
             bitmask.reverse_bits(LANES)
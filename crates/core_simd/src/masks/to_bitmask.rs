@@ -16,8 +16,39 @@ where
 /// Converts masks to and from integer bitmasks.
 ///
 /// Each bit of the bitmask corresponds to a mask lane, starting with the LSB.
+/// Bits beyond the number of lanes are zero, and are ignored when converting a bitmask to a mask.
+///
+/// The bit order of [`to_bitmask`](ToBitMask::to_bitmask) is the same on every target: lane `i`
+/// is the bit with value `1 << i`. The layout of the bitmask in memory, however, depends on the
+/// target's endianness. For file formats and network protocols, use the `_le` or `_be` variants,
+/// which have the same layout in memory on every target:
+///
+/// | Method                             | Lane 0 is the...                        |
+/// |------------------------------------|-----------------------------------------|
+/// | `to_bitmask`, `to_bitmask_lsb0`    | least significant bit of the integer    |
+/// | `to_bitmask_msb0`                  | most significant bit of the integer     |
+/// | `to_bitmask_le`                    | least significant bit of the first byte |
+/// | `to_bitmask_be`                    | most significant bit of the first byte  |
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{mask8x16, ToBitMask};
+/// let mut mask = mask8x16::splat(false);
+/// mask.set(0, true);
+/// mask.set(9, true);
+/// assert_eq!(mask.to_bitmask_lsb0(), 0b0000_0010_0000_0001);
+/// assert_eq!(mask.to_bitmask_msb0(), 0b1000_0000_0100_0000);
+/// assert_eq!(mask.to_bitmask_le().to_ne_bytes(), [0b0000_0001, 0b0000_0010]);
+/// assert_eq!(mask.to_bitmask_be().to_ne_bytes(), [0b1000_0000, 0b0100_0000]);
+/// ```
 pub trait ToBitMask: Sealed {
     /// The integer bitmask type.
+    ///
+    /// This is the smallest unsigned integer with at least one bit per lane (but at least `u8`),
+    /// or `[u8; 32]` for masks with 256 lanes, where the first byte holds lanes 0 through 7.
     type BitMask;
 
     /// Converts a mask to a bitmask.
@@ -25,6 +56,51 @@ pub trait ToBitMask: Sealed {
 
     /// Converts a bitmask to a mask.
     fn from_bitmask(bitmask: Self::BitMask) -> Self;
+
+    /// Converts a mask to a bitmask, where lane `i` is bit `i`, counting from the least
+    /// significant bit.
+    ///
+    /// This is the same as [`to_bitmask`](ToBitMask::to_bitmask).
+    fn to_bitmask_lsb0(self) -> Self::BitMask;
+
+    /// Converts a bitmask to a mask, where lane `i` is bit `i`, counting from the least
+    /// significant bit.
+    ///
+    /// This is the same as [`from_bitmask`](ToBitMask::from_bitmask).
+    fn from_bitmask_lsb0(bitmask: Self::BitMask) -> Self;
+
+    /// Converts a mask to a bitmask, where lane `i` is bit `i`, counting from the most
+    /// significant bit.
+    ///
+    /// If the bitmask has more bits than the mask has lanes, the least significant bits are zero.
+    fn to_bitmask_msb0(self) -> Self::BitMask;
+
+    /// Converts a bitmask to a mask, where lane `i` is bit `i`, counting from the most
+    /// significant bit.
+    ///
+    /// If the bitmask has more bits than the mask has lanes, the least significant bits are
+    /// ignored.
+    fn from_bitmask_msb0(bitmask: Self::BitMask) -> Self;
+
+    /// Converts a mask to a bitmask, where lane 0 is the least significant bit of the first
+    /// byte in memory.
+    ///
+    /// On little-endian targets, this is the same as [`to_bitmask`](ToBitMask::to_bitmask).
+    fn to_bitmask_le(self) -> Self::BitMask;
+
+    /// Converts a bitmask to a mask, where lane 0 is the least significant bit of the first
+    /// byte in memory.
+    fn from_bitmask_le(bitmask: Self::BitMask) -> Self;
+
+    /// Converts a mask to a bitmask, where lane 0 is the most significant bit of the first
+    /// byte in memory.
+    ///
+    /// On big-endian targets, this is the same as [`to_bitmask_msb0`](ToBitMask::to_bitmask_msb0).
+    fn to_bitmask_be(self) -> Self::BitMask;
+
+    /// Converts a bitmask to a mask, where lane 0 is the most significant bit of the first
+    /// byte in memory.
+    fn from_bitmask_be(bitmask: Self::BitMask) -> Self;
 }
 
 /// Converts masks to and from byte array bitmasks.
@@ -42,6 +118,61 @@ pub trait ToBitMaskArray: Sealed {
     fn from_bitmask_array(bitmask: [u8; Self::BYTES]) -> Self;
 }
 
+/// Bit and byte order conversions of bitmasks.
+///
+/// Byte order conversions are their own inverse, so `to_le` and `to_be` also
+/// convert back to native order.
+trait BitOrder: Copy {
+    fn reverse_bits(self) -> Self;
+    fn to_le(self) -> Self;
+    fn to_be(self) -> Self;
+}
+
+macro_rules! impl_bit_order {
+    { $($int:ty),* } => {
+        $(
+        impl BitOrder for $int {
+            #[inline]
+            fn reverse_bits(self) -> Self {
+                <$int>::reverse_bits(self)
+            }
+
+            #[inline]
+            fn to_le(self) -> Self {
+                <$int>::to_le(self)
+            }
+
+            #[inline]
+            fn to_be(self) -> Self {
+                <$int>::to_be(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_bit_order! { u8, u16, u32, u64, u128 }
+
+// Byte array bitmasks are always stored least significant byte first.
+impl<const N: usize> BitOrder for [u8; N] {
+    #[inline]
+    fn reverse_bits(mut self) -> Self {
+        self.reverse();
+        self.map(u8::reverse_bits)
+    }
+
+    #[inline]
+    fn to_le(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn to_be(mut self) -> Self {
+        self.reverse();
+        self
+    }
+}
+
 macro_rules! impl_integer_intrinsic {
     { $(impl ToBitMask<BitMask=$int:ty> for Mask<_, $lanes:literal>)* } => {
         $(
@@ -57,6 +188,46 @@ macro_rules! impl_integer_intrinsic {
             fn from_bitmask(bitmask: $int) -> Self {
                 Self(mask_impl::Mask::from_bitmask_integer(bitmask))
             }
+
+            #[inline]
+            fn to_bitmask_lsb0(self) -> $int {
+                self.to_bitmask()
+            }
+
+            #[inline]
+            fn from_bitmask_lsb0(bitmask: $int) -> Self {
+                Self::from_bitmask(bitmask)
+            }
+
+            #[inline]
+            fn to_bitmask_msb0(self) -> $int {
+                BitOrder::reverse_bits(self.to_bitmask())
+            }
+
+            #[inline]
+            fn from_bitmask_msb0(bitmask: $int) -> Self {
+                Self::from_bitmask(BitOrder::reverse_bits(bitmask))
+            }
+
+            #[inline]
+            fn to_bitmask_le(self) -> $int {
+                BitOrder::to_le(self.to_bitmask())
+            }
+
+            #[inline]
+            fn from_bitmask_le(bitmask: $int) -> Self {
+                Self::from_bitmask(BitOrder::to_le(bitmask))
+            }
+
+            #[inline]
+            fn to_bitmask_be(self) -> $int {
+                BitOrder::to_be(self.to_bitmask_msb0())
+            }
+
+            #[inline]
+            fn from_bitmask_be(bitmask: $int) -> Self {
+                Self::from_bitmask_msb0(BitOrder::to_be(bitmask))
+            }
         }
         )*
     }
@@ -70,6 +241,69 @@ impl_integer_intrinsic! {
     impl ToBitMask<BitMask=u16> for Mask<_, 16>
     impl ToBitMask<BitMask=u32> for Mask<_, 32>
     impl ToBitMask<BitMask=u64> for Mask<_, 64>
+    impl ToBitMask<BitMask=u128> for Mask<_, 128>
+    impl ToBitMask<BitMask=[u8; 32]> for Mask<_, 256>
+}
+
+#[cfg(feature = "all_lane_counts")]
+impl_integer_intrinsic! {
+    impl ToBitMask<BitMask=u8> for Mask<_, 3>
+    impl ToBitMask<BitMask=u8> for Mask<_, 5>
+    impl ToBitMask<BitMask=u8> for Mask<_, 6>
+    impl ToBitMask<BitMask=u8> for Mask<_, 7>
+    impl ToBitMask<BitMask=u16> for Mask<_, 9>
+    impl ToBitMask<BitMask=u16> for Mask<_, 10>
+    impl ToBitMask<BitMask=u16> for Mask<_, 11>
+    impl ToBitMask<BitMask=u16> for Mask<_, 12>
+    impl ToBitMask<BitMask=u16> for Mask<_, 13>
+    impl ToBitMask<BitMask=u16> for Mask<_, 14>
+    impl ToBitMask<BitMask=u16> for Mask<_, 15>
+    impl ToBitMask<BitMask=u32> for Mask<_, 17>
+    impl ToBitMask<BitMask=u32> for Mask<_, 18>
+    impl ToBitMask<BitMask=u32> for Mask<_, 19>
+    impl ToBitMask<BitMask=u32> for Mask<_, 20>
+    impl ToBitMask<BitMask=u32> for Mask<_, 21>
+    impl ToBitMask<BitMask=u32> for Mask<_, 22>
+    impl ToBitMask<BitMask=u32> for Mask<_, 23>
+    impl ToBitMask<BitMask=u32> for Mask<_, 24>
+    impl ToBitMask<BitMask=u32> for Mask<_, 25>
+    impl ToBitMask<BitMask=u32> for Mask<_, 26>
+    impl ToBitMask<BitMask=u32> for Mask<_, 27>
+    impl ToBitMask<BitMask=u32> for Mask<_, 28>
+    impl ToBitMask<BitMask=u32> for Mask<_, 29>
+    impl ToBitMask<BitMask=u32> for Mask<_, 30>
+    impl ToBitMask<BitMask=u32> for Mask<_, 31>
+    impl ToBitMask<BitMask=u64> for Mask<_, 33>
+    impl ToBitMask<BitMask=u64> for Mask<_, 34>
+    impl ToBitMask<BitMask=u64> for Mask<_, 35>
+    impl ToBitMask<BitMask=u64> for Mask<_, 36>
+    impl ToBitMask<BitMask=u64> for Mask<_, 37>
+    impl ToBitMask<BitMask=u64> for Mask<_, 38>
+    impl ToBitMask<BitMask=u64> for Mask<_, 39>
+    impl ToBitMask<BitMask=u64> for Mask<_, 40>
+    impl ToBitMask<BitMask=u64> for Mask<_, 41>
+    impl ToBitMask<BitMask=u64> for Mask<_, 42>
+    impl ToBitMask<BitMask=u64> for Mask<_, 43>
+    impl ToBitMask<BitMask=u64> for Mask<_, 44>
+    impl ToBitMask<BitMask=u64> for Mask<_, 45>
+    impl ToBitMask<BitMask=u64> for Mask<_, 46>
+    impl ToBitMask<BitMask=u64> for Mask<_, 47>
+    impl ToBitMask<BitMask=u64> for Mask<_, 48>
+    impl ToBitMask<BitMask=u64> for Mask<_, 49>
+    impl ToBitMask<BitMask=u64> for Mask<_, 50>
+    impl ToBitMask<BitMask=u64> for Mask<_, 51>
+    impl ToBitMask<BitMask=u64> for Mask<_, 52>
+    impl ToBitMask<BitMask=u64> for Mask<_, 53>
+    impl ToBitMask<BitMask=u64> for Mask<_, 54>
+    impl ToBitMask<BitMask=u64> for Mask<_, 55>
+    impl ToBitMask<BitMask=u64> for Mask<_, 56>
+    impl ToBitMask<BitMask=u64> for Mask<_, 57>
+    impl ToBitMask<BitMask=u64> for Mask<_, 58>
+    impl ToBitMask<BitMask=u64> for Mask<_, 59>
+    impl ToBitMask<BitMask=u64> for Mask<_, 60>
+    impl ToBitMask<BitMask=u64> for Mask<_, 61>
+    impl ToBitMask<BitMask=u64> for Mask<_, 62>
+    impl ToBitMask<BitMask=u64> for Mask<_, 63>
 }
 
 /// Returns the minimum number of bytes in a bitmask with `lanes` lanes.
@@ -7,7 +7,13 @@ pub(crate) mod intrinsics;
 mod to_bytes;
 
 mod alias;
+mod aligned;
+#[cfg(feature = "alloc")]
+mod buffer;
+mod cache;
 mod cast;
+pub(crate) mod clmul;
+mod conflict;
 mod elements;
 mod eq;
 mod fmt;
@@ -16,21 +22,31 @@ mod lane_count;
 mod masks;
 mod ops;
 mod ord;
+mod relaxed;
 mod select;
+pub mod slice;
+mod sort;
+mod swizzle_dyn;
 mod vector;
 mod vendor;
 
 #[doc = include_str!("core_simd_docs.md")]
 pub mod simd {
+    pub(crate) use crate::core_simd::clmul;
     pub(crate) use crate::core_simd::intrinsics;
 
     pub use crate::core_simd::alias::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::core_simd::buffer::*;
+    pub use crate::core_simd::cache::*;
     pub use crate::core_simd::cast::*;
     pub use crate::core_simd::elements::*;
     pub use crate::core_simd::eq::*;
     pub use crate::core_simd::lane_count::{LaneCount, SupportedLaneCount};
     pub use crate::core_simd::masks::*;
     pub use crate::core_simd::ord::*;
+    pub use crate::core_simd::slice;
+    pub use crate::core_simd::sort::*;
     pub use crate::core_simd::swizzle::*;
     pub use crate::core_simd::vector::*;
 }
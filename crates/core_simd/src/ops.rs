@@ -6,6 +6,7 @@ use core::ops::{Div, Rem, Sub};
 use core::ops::{Shl, Shr};
 
 mod assign;
+mod const_ops;
 mod deref;
 mod unary;
 
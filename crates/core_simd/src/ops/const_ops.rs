@@ -0,0 +1,273 @@
+//! Lanewise operations that can be evaluated in const contexts.
+//!
+//! Trait methods can't be called in const contexts, and neither can the intrinsics backing the
+//! operators, so these are implemented one lane at a time. They are intended for building
+//! constants, such as lookup tables; outside of const contexts, prefer the operators.
+use crate::simd::{LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+
+/// Implements a lanewise binary operation.
+macro_rules! binary {
+    { $ty:ty => $(#[$attr:meta])* fn $name:ident($lhs:ident, $rhs:ident) $body:block } => {
+        $(#[$attr])*
+        #[inline]
+        #[must_use = "method returns a new vector and does not mutate the original inputs"]
+        pub const fn $name(self, rhs: Self) -> Self {
+            let lhs = self.to_array();
+            let rhs = rhs.to_array();
+            let mut output = [0 as $ty; LANES];
+            let mut i = 0;
+            while i < LANES {
+                let ($lhs, $rhs) = (lhs[i], rhs[i]);
+                output[i] = $body;
+                i += 1;
+            }
+            Self::from_array(output)
+        }
+    }
+}
+
+/// Implements a lanewise unary operation.
+macro_rules! unary {
+    { $ty:ty => $(#[$attr:meta])* fn $name:ident($x:ident) $body:block } => {
+        $(#[$attr])*
+        #[inline]
+        #[must_use = "method returns a new vector and does not mutate the original value"]
+        pub const fn $name(self) -> Self {
+            let x = self.to_array();
+            let mut output = [0 as $ty; LANES];
+            let mut i = 0;
+            while i < LANES {
+                let $x = x[i];
+                output[i] = $body;
+                i += 1;
+            }
+            Self::from_array(output)
+        }
+    }
+}
+
+/// Implements a lanewise comparison.
+macro_rules! compare {
+    { $ty:ty => $(#[$attr:meta])* fn $name:ident($lhs:ident, $rhs:ident) $body:block } => {
+        $(#[$attr])*
+        #[inline]
+        #[must_use = "method returns a new mask and does not mutate the original inputs"]
+        pub const fn $name(self, rhs: Self) -> Mask<<$ty as SimdElement>::Mask, LANES> {
+            let lhs = self.to_array();
+            let rhs = rhs.to_array();
+            let mut output = [false; LANES];
+            let mut i = 0;
+            while i < LANES {
+                let ($lhs, $rhs) = (lhs[i], rhs[i]);
+                output[i] = $body;
+                i += 1;
+            }
+            Mask::from_array(output)
+        }
+    }
+}
+
+/// Implements the comparisons shared by all element types.
+macro_rules! comparisons {
+    { $ty:ty } => {
+        compare! { $ty =>
+            /// Test if each lane is equal to the corresponding lane in `rhs`, in a const context.
+            fn const_eq(a, b) { a == b }
+        }
+
+        compare! { $ty =>
+            /// Test if each lane is not equal to the corresponding lane in `rhs`, in a const
+            /// context.
+            fn const_ne(a, b) { a != b }
+        }
+
+        compare! { $ty =>
+            /// Test if each lane is less than the corresponding lane in `rhs`, in a const context.
+            fn const_lt(a, b) { a < b }
+        }
+
+        compare! { $ty =>
+            /// Test if each lane is less than or equal to the corresponding lane in `rhs`, in a
+            /// const context.
+            fn const_le(a, b) { a <= b }
+        }
+
+        compare! { $ty =>
+            /// Test if each lane is greater than the corresponding lane in `rhs`, in a const
+            /// context.
+            fn const_gt(a, b) { a > b }
+        }
+
+        compare! { $ty =>
+            /// Test if each lane is greater than or equal to the corresponding lane in `rhs`, in
+            /// a const context.
+            fn const_ge(a, b) { a >= b }
+        }
+    }
+}
+
+macro_rules! impl_integer {
+    { $($ty:ty),* } => {
+        $(
+        impl<const LANES: usize> Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            binary! { $ty =>
+                /// Lanewise wrapping addition, in a const context.
+                ///
+                /// # Examples
+                /// ```
+                /// # #![feature(portable_simd)]
+                /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+                /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+                /// # use simd::u32x4;
+                /// const OFFSETS: u32x4 = u32x4::from_array([0, 1, 2, 3]).const_add(u32x4::splat(4));
+                /// assert_eq!(OFFSETS.to_array(), [4, 5, 6, 7]);
+                /// ```
+                fn const_add(a, b) { a.wrapping_add(b) }
+            }
+
+            binary! { $ty =>
+                /// Lanewise wrapping subtraction, in a const context.
+                fn const_sub(a, b) { a.wrapping_sub(b) }
+            }
+
+            binary! { $ty =>
+                /// Lanewise wrapping multiplication, in a const context.
+                fn const_mul(a, b) { a.wrapping_mul(b) }
+            }
+
+            binary! { $ty =>
+                /// Lanewise bitwise "and", in a const context.
+                fn const_and(a, b) { a & b }
+            }
+
+            binary! { $ty =>
+                /// Lanewise bitwise "or", in a const context.
+                fn const_or(a, b) { a | b }
+            }
+
+            binary! { $ty =>
+                /// Lanewise bitwise "xor", in a const context.
+                fn const_xor(a, b) { a ^ b }
+            }
+
+            binary! { $ty =>
+                /// Lanewise left shift, in a const context.
+                ///
+                /// As with the `<<` operator, the shift amount wraps at the bit width.
+                fn const_shl(a, b) { a.wrapping_shl(b as u32) }
+            }
+
+            binary! { $ty =>
+                /// Lanewise right shift, in a const context.
+                ///
+                /// As with the `>>` operator, the shift amount wraps at the bit width.
+                fn const_shr(a, b) { a.wrapping_shr(b as u32) }
+            }
+
+            binary! { $ty =>
+                /// Lanewise minimum, in a const context.
+                fn const_min(a, b) { if a < b { a } else { b } }
+            }
+
+            binary! { $ty =>
+                /// Lanewise maximum, in a const context.
+                fn const_max(a, b) { if a > b { a } else { b } }
+            }
+
+            unary! { $ty =>
+                /// Lanewise bitwise "not", in a const context.
+                fn const_not(x) { !x }
+            }
+
+            comparisons! { $ty }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_signed {
+    { $($ty:ty),* } => {
+        $(
+        impl<const LANES: usize> Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            unary! { $ty =>
+                /// Lanewise wrapping negation, in a const context.
+                fn const_neg(x) { x.wrapping_neg() }
+            }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_float {
+    { $($ty:ty),* } => {
+        $(
+        impl<const LANES: usize> Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            binary! { $ty =>
+                /// Lanewise addition, in a const context.
+                ///
+                /// # Examples
+                /// ```
+                /// # #![feature(portable_simd)]
+                /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+                /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+                /// # use simd::f32x4;
+                /// const COEFFS: f32x4 = f32x4::from_array([1., 2., 3., 4.]).const_add(f32x4::splat(0.5));
+                /// assert_eq!(COEFFS.to_array(), [1.5, 2.5, 3.5, 4.5]);
+                /// ```
+                fn const_add(a, b) { a + b }
+            }
+
+            binary! { $ty =>
+                /// Lanewise subtraction, in a const context.
+                fn const_sub(a, b) { a - b }
+            }
+
+            binary! { $ty =>
+                /// Lanewise multiplication, in a const context.
+                fn const_mul(a, b) { a * b }
+            }
+
+            binary! { $ty =>
+                /// Lanewise division, in a const context.
+                fn const_div(a, b) { a / b }
+            }
+
+            binary! { $ty =>
+                /// Lanewise minimum, in a const context.
+                ///
+                /// If one of the values is NaN, then the other value is returned.
+                #[allow(clippy::eq_op)]
+                fn const_min(a, b) { if a < b || b != b { a } else { b } }
+            }
+
+            binary! { $ty =>
+                /// Lanewise maximum, in a const context.
+                ///
+                /// If one of the values is NaN, then the other value is returned.
+                #[allow(clippy::eq_op)]
+                fn const_max(a, b) { if a > b || b != b { a } else { b } }
+            }
+
+            unary! { $ty =>
+                /// Lanewise negation, in a const context.
+                fn const_neg(x) { -x }
+            }
+
+            comparisons! { $ty }
+        }
+        )*
+    }
+}
+
+impl_integer! { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
+impl_signed! { i8, i16, i32, i64, isize }
+impl_float! { f32, f64 }
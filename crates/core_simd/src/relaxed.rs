@@ -0,0 +1,167 @@
+//! Operations with relaxed, implementation-defined semantics.
+//!
+//! These operations produce the same results as their strict counterparts for most inputs, but
+//! leave the results for some inputs unspecified. This permits the fastest instruction available
+//! on each target, such as WebAssembly's relaxed SIMD instructions. The results for those inputs
+//! may differ between targets, between target features, and between optimization levels, so they
+//! must not be relied on.
+use crate::simd::{LaneCount, Simd, SimdPartialOrd, SupportedLaneCount};
+
+#[cfg(all(target_arch = "wasm32", target_feature = "relaxed-simd"))]
+use crate::simd::SimdElement;
+
+#[cfg(all(target_arch = "wasm32", target_feature = "relaxed-simd"))]
+use core::arch::wasm32::{self, v128};
+
+/// Reinterprets a vector as a `v128`, if it is 128 bits wide.
+#[cfg(all(target_arch = "wasm32", target_feature = "relaxed-simd"))]
+#[inline]
+fn to_v128<T, const LANES: usize>(vector: Simd<T, LANES>) -> Option<v128>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    if core::mem::size_of::<Simd<T, LANES>>() == core::mem::size_of::<v128>() {
+        // Safety: the vector and `v128` are the same size
+        Some(unsafe { core::mem::transmute_copy(&vector) })
+    } else {
+        None
+    }
+}
+
+/// Reinterprets a `v128` as a vector, which must be 128 bits wide.
+#[cfg(all(target_arch = "wasm32", target_feature = "relaxed-simd"))]
+#[inline]
+fn from_v128<T, const LANES: usize>(vector: v128) -> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert_eq!(
+        core::mem::size_of::<Simd<T, LANES>>(),
+        core::mem::size_of::<v128>()
+    );
+    // Safety: the vector and `v128` are the same size
+    unsafe { core::mem::transmute_copy(&vector) }
+}
+
+macro_rules! impl_relaxed_float {
+    { $($ty:ty: $madd:ident, $min:ident, $max:ident;)* } => {
+        $(
+        impl<const LANES: usize> Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Multiplies by `a` and adds `b`, with either one or two roundings.
+            ///
+            /// The result is either that of a fused multiply-add, or of a multiplication followed
+            /// by an addition. Which one is unspecified, and may vary by target.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            #[doc = concat!("let x = Simd::<", stringify!($ty), ", 4>::from_array([1., 2., 3., 4.]);")]
+            /// let y = x.relaxed_mul_add(Simd::splat(2.), Simd::splat(0.5));
+            /// assert_eq!(y.to_array(), [2.5, 4.5, 6.5, 8.5]);
+            /// ```
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn relaxed_mul_add(self, a: Self, b: Self) -> Self {
+                #[cfg(all(target_arch = "wasm32", target_feature = "relaxed-simd"))]
+                if let (Some(x), Some(a), Some(b)) = (to_v128(self), to_v128(a), to_v128(b)) {
+                    return from_v128(wasm32::$madd(x, a, b));
+                }
+                self * a + b
+            }
+
+            /// Returns the minimum of each lane.
+            ///
+            /// If either lane is NaN, or the lanes are zeros of opposite signs, the result is
+            /// unspecified and may be either lane or NaN.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            #[doc = concat!("let a = Simd::<", stringify!($ty), ", 4>::from_array([1., -2., 3., 4.]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($ty), ", 4>::from_array([2., -3., 0., 4.]);")]
+            /// assert_eq!(a.relaxed_min(b).to_array(), [1., -3., 0., 4.]);
+            /// ```
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn relaxed_min(self, other: Self) -> Self {
+                #[cfg(all(target_arch = "wasm32", target_feature = "relaxed-simd"))]
+                if let (Some(a), Some(b)) = (to_v128(self), to_v128(other)) {
+                    return from_v128(wasm32::$min(a, b));
+                }
+                self.simd_lt(other).select(self, other)
+            }
+
+            /// Returns the maximum of each lane.
+            ///
+            /// If either lane is NaN, or the lanes are zeros of opposite signs, the result is
+            /// unspecified and may be either lane or NaN.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            #[doc = concat!("let a = Simd::<", stringify!($ty), ", 4>::from_array([1., -2., 3., 4.]);")]
+            #[doc = concat!("let b = Simd::<", stringify!($ty), ", 4>::from_array([2., -3., 0., 4.]);")]
+            /// assert_eq!(a.relaxed_max(b).to_array(), [2., -2., 3., 4.]);
+            /// ```
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn relaxed_max(self, other: Self) -> Self {
+                #[cfg(all(target_arch = "wasm32", target_feature = "relaxed-simd"))]
+                if let (Some(a), Some(b)) = (to_v128(self), to_v128(other)) {
+                    return from_v128(wasm32::$max(a, b));
+                }
+                self.simd_gt(other).select(self, other)
+            }
+        }
+        )*
+    }
+}
+
+impl_relaxed_float! {
+    f32: f32x4_relaxed_madd, f32x4_relaxed_min, f32x4_relaxed_max;
+    f64: f64x2_relaxed_madd, f64x2_relaxed_min, f64x2_relaxed_max;
+}
+
+impl<const LANES: usize> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Swizzles the lanes of a vector, using a vector of runtime lane indices.
+    ///
+    /// Lane `i` of the result is `self[idxs[i]]` if `idxs[i]` is less than `LANES`. Otherwise,
+    /// the value of the lane is unspecified.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::u8x4;
+    /// let table = u8x4::from_array([10, 11, 12, 13]);
+    /// let idxs = u8x4::from_array([3, 0, 0, 2]);
+    /// assert_eq!(table.relaxed_swizzle_dyn(idxs).to_array(), [13, 10, 10, 12]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn relaxed_swizzle_dyn(self, idxs: Self) -> Self {
+        #[cfg(all(target_arch = "wasm32", target_feature = "relaxed-simd"))]
+        if let (Some(table), Some(idxs)) = (to_v128(self), to_v128(idxs)) {
+            return from_v128(wasm32::i8x16_relaxed_swizzle(table, idxs));
+        }
+        self.swizzle_dyn(idxs)
+    }
+}
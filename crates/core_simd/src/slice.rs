@@ -0,0 +1,713 @@
+//! Reductions and transforms over slices.
+//!
+//! Each function processes its slice in blocks of `LANES` elements, starting from the first
+//! element. Reductions keep four independent accumulators, so that consecutive blocks don't
+//! wait on each other. A final partial block is loaded and stored with a mask, so that the
+//! missing lanes are never read or written.
+//!
+//! # Summation order
+//!
+//! Floating point addition is not associative, so [`sum`] and [`dot`] add their terms in an
+//! order that depends only on the length of the slice and `LANES`:
+//!
+//! 1. Four accumulators start with every lane set to `-0.0`.
+//! 2. In increasing order of `i`, element (or product) `i` is added to lane `i % LANES` of
+//!    accumulator `i / LANES % 4`.
+//! 3. The accumulators are added as `(acc[0] + acc[1]) + (acc[2] + acc[3])`.
+//! 4. Starting from `-0.0`, the lanes of the result are added in increasing order.
+//!
+//! The result is the same on every target, and doesn't depend on the alignment of the slice.
+//! Integer sums wrap on overflow.
+use crate::simd::{
+    LaneCount, Mask, Simd, SimdElement, SimdFloat, SimdInt, SimdOrd, SimdPartialEq, SimdPartialOrd,
+    SupportedLaneCount,
+};
+
+mod sealed {
+    use super::*;
+
+    /// Lanewise and scalar operations, which differ between integers and floats.
+    pub trait Ops: SimdElement + PartialOrd {
+        /// The identity for `add`.
+        const ZERO: Self;
+        /// The identity for `min`.
+        const MIN_IDENTITY: Self;
+        /// The identity for `max`.
+        const MAX_IDENTITY: Self;
+
+        fn add(self, other: Self) -> Self;
+        fn min(self, other: Self) -> Self;
+        fn max(self, other: Self) -> Self;
+
+        fn simd_add<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+        fn simd_mul<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+        fn simd_min<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+        fn simd_max<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+        fn simd_eq<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Mask<Self::Mask, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+        fn simd_lt<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Mask<Self::Mask, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+        fn simd_gt<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Mask<Self::Mask, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+    }
+}
+
+use sealed::Ops;
+
+/// Elements of slices that the functions in this module can operate on.
+///
+/// This trait is sealed, and implemented for every integer and floating point type.
+pub trait SliceElement: Ops {}
+
+macro_rules! impl_ops {
+    { $zero:expr, $min_identity:expr, $max_identity:expr, $add:expr } => {
+        const ZERO: Self = $zero;
+        const MIN_IDENTITY: Self = $min_identity;
+        const MAX_IDENTITY: Self = $max_identity;
+
+        #[inline]
+        fn add(self, other: Self) -> Self {
+            $add(self, other)
+        }
+
+        #[inline]
+        fn simd_add<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            a + b
+        }
+
+        #[inline]
+        fn simd_mul<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            a * b
+        }
+
+        #[inline]
+        fn simd_min<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            a.simd_min(b)
+        }
+
+        #[inline]
+        fn simd_max<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            a.simd_max(b)
+        }
+
+        #[inline]
+        fn simd_eq<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Mask<Self::Mask, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            SimdPartialEq::simd_eq(a, b)
+        }
+
+        #[inline]
+        fn simd_lt<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Mask<Self::Mask, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            SimdPartialOrd::simd_lt(a, b)
+        }
+
+        #[inline]
+        fn simd_gt<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Mask<Self::Mask, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            SimdPartialOrd::simd_gt(a, b)
+        }
+    }
+}
+
+macro_rules! impl_integer {
+    { $($ty:ty),* } => {
+        $(
+        impl Ops for $ty {
+            impl_ops! { 0, <$ty>::MAX, <$ty>::MIN, <$ty>::wrapping_add }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                Ord::min(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                Ord::max(self, other)
+            }
+        }
+
+        impl SliceElement for $ty {}
+        )*
+    }
+}
+
+macro_rules! impl_float {
+    { $($ty:ty),* } => {
+        $(
+        // NaN is the identity for `min` and `max`, which ignore NaNs
+        impl Ops for $ty {
+            impl_ops! { -0.0, <$ty>::NAN, <$ty>::NAN, core::ops::Add::add }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                <$ty>::min(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                <$ty>::max(self, other)
+            }
+        }
+
+        impl SliceElement for $ty {}
+        )*
+    }
+}
+
+impl_integer! { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
+impl_float! { f32, f64 }
+
+/// Returns the lane indices `0, 1, ..., LANES - 1`.
+#[inline]
+fn lane_indices<const LANES: usize>() -> Simd<usize, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    Simd::from_array(core::array::from_fn(|i| i))
+}
+
+/// Returns a mask of the first `len` lanes.
+#[inline]
+fn enable<const LANES: usize>(len: usize) -> Mask<isize, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    lane_indices().simd_lt(Simd::splat(len))
+}
+
+/// Loads the enabled lanes from the start of `slice`, and `or` in the others.
+#[inline]
+fn load_or<T, const LANES: usize>(slice: &[T], enable: Mask<isize, LANES>, or: T) -> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    Simd::gather_select(slice, enable, lane_indices(), Simd::splat(or))
+}
+
+/// Calls `full` or `partial` with each block of `LANES` elements of a slice of length `len`,
+/// and the accumulator for that block.
+///
+/// Block `i` starts at index `i * LANES` and uses accumulator `i % 4`. Only the last block may
+/// be partial.
+#[inline]
+fn for_each_block<A, const LANES: usize>(
+    len: usize,
+    accs: &mut [A; 4],
+    mut full: impl FnMut(&mut A, usize),
+    partial: impl FnOnce(&mut A, usize),
+) where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let blocks = len / LANES;
+    let mut block = 0;
+    while block + 4 <= blocks {
+        for (i, acc) in accs.iter_mut().enumerate() {
+            full(acc, (block + i) * LANES);
+        }
+        block += 4;
+    }
+    while block < blocks {
+        full(&mut accs[block % 4], block * LANES);
+        block += 1;
+    }
+    if blocks * LANES < len {
+        partial(&mut accs[blocks % 4], blocks * LANES);
+    }
+}
+
+/// Adds the lanes of the accumulators, in the order described in the [module documentation](self).
+#[inline]
+fn reduce_sum<T, const LANES: usize>(accs: [Simd<T, LANES>; 4]) -> T
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let [a, b, c, d] = accs;
+    let sum = T::simd_add(T::simd_add(a, b), T::simd_add(c, d));
+    sum.to_array().into_iter().fold(T::ZERO, T::add)
+}
+
+/// Returns the sum of the elements of `slice`, adding `LANES` elements at a time.
+///
+/// Integer sums wrap on overflow. Floating point sums are added in the order described in the
+/// [module documentation](self).
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// let data: Vec<f32> = (1..=100).map(|x| x as f32).collect();
+/// assert_eq!(slice::sum::<_, 8>(&data), 5050.);
+/// ```
+#[inline]
+#[must_use]
+pub fn sum<T, const LANES: usize>(slice: &[T]) -> T
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut accs = [Simd::<T, LANES>::splat(T::ZERO); 4];
+    for_each_block::<_, LANES>(
+        slice.len(),
+        &mut accs,
+        |acc, start| *acc = T::simd_add(*acc, Simd::from_slice(&slice[start..])),
+        |acc, start| {
+            let tail = &slice[start..];
+            *acc = T::simd_add(*acc, load_or(tail, enable(tail.len()), T::ZERO));
+        },
+    );
+    reduce_sum(accs)
+}
+
+/// Returns the dot product of `a` and `b`, multiplying and adding `LANES` elements at a time.
+///
+/// Each product is rounded before it is added. Integer sums and products wrap on overflow.
+/// Floating point products are added in the order described in the
+/// [module documentation](self).
+///
+/// # Panics
+/// Panics if `a` and `b` have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// let a = [1., 2., 3., 4., 5., 6., 7., 8., 9.];
+/// let b = [9., 8., 7., 6., 5., 4., 3., 2., 1.];
+/// assert_eq!(slice::dot::<f64, 4>(&a, &b), 165.);
+/// ```
+#[inline]
+#[must_use]
+pub fn dot<T, const LANES: usize>(a: &[T], b: &[T]) -> T
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let mut accs = [Simd::<T, LANES>::splat(T::ZERO); 4];
+    for_each_block::<_, LANES>(
+        a.len(),
+        &mut accs,
+        |acc, start| {
+            let product = T::simd_mul(Simd::from_slice(&a[start..]), Simd::from_slice(&b[start..]));
+            *acc = T::simd_add(*acc, product);
+        },
+        |acc, start| {
+            let enable = enable(a.len() - start);
+            let product = T::simd_mul(
+                load_or(&a[start..], enable, T::ZERO),
+                load_or(&b[start..], enable, T::ZERO),
+            );
+            let product = enable.cast().select(product, Simd::splat(T::ZERO));
+            *acc = T::simd_add(*acc, product);
+        },
+    );
+    reduce_sum(accs)
+}
+
+/// Reduces `slice` with a lanewise operation that has the identity `identity`.
+#[inline]
+fn reduce_by<T, const LANES: usize>(
+    slice: &[T],
+    identity: T,
+    simd_op: impl Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+    op: impl Fn(T, T) -> T,
+) -> Option<T>
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    if slice.is_empty() {
+        return None;
+    }
+    let mut accs = [Simd::<T, LANES>::splat(identity); 4];
+    for_each_block::<_, LANES>(
+        slice.len(),
+        &mut accs,
+        |acc, start| *acc = simd_op(*acc, Simd::from_slice(&slice[start..])),
+        |acc, start| {
+            let tail = &slice[start..];
+            *acc = simd_op(*acc, load_or(tail, enable(tail.len()), identity));
+        },
+    );
+    let [a, b, c, d] = accs;
+    Some(
+        simd_op(simd_op(a, b), simd_op(c, d))
+            .to_array()
+            .into_iter()
+            .fold(identity, op),
+    )
+}
+
+/// Returns the minimum element of `slice`, comparing `LANES` elements at a time, or `None` if
+/// it is empty.
+///
+/// As with [`f32::min`], NaNs are ignored, unless every element is NaN.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// assert_eq!(slice::min::<_, 4>(&[3., f32::NAN, -1., 7., 2.]), Some(-1.));
+/// assert_eq!(slice::min::<u8, 4>(&[]), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn min<T, const LANES: usize>(slice: &[T]) -> Option<T>
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    reduce_by::<T, LANES>(slice, T::MIN_IDENTITY, T::simd_min, T::min)
+}
+
+/// Returns the maximum element of `slice`, comparing `LANES` elements at a time, or `None` if
+/// it is empty.
+///
+/// As with [`f32::max`], NaNs are ignored, unless every element is NaN.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// assert_eq!(slice::max::<_, 4>(&[3., f32::NAN, -1., 7., 2.]), Some(7.));
+/// assert_eq!(slice::max::<u8, 4>(&[]), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn max<T, const LANES: usize>(slice: &[T]) -> Option<T>
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    reduce_by::<T, LANES>(slice, T::MAX_IDENTITY, T::simd_max, T::max)
+}
+
+/// Returns the index of the first element of `slice` that no other element is `better` than.
+///
+/// Each lane of each accumulator tracks the best element it has seen, and its index, which is
+/// `usize::MAX` until an element that is equal to itself has been seen.
+#[inline]
+fn position_by<T, const LANES: usize>(
+    slice: &[T],
+    better: impl Fn(Simd<T, LANES>, Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+) -> Option<usize>
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let unset = Simd::splat(usize::MAX);
+    let update = |acc: &mut (Simd<T, LANES>, Simd<usize, LANES>),
+                  values: Simd<T, LANES>,
+                  start: usize,
+                  enable: Mask<isize, LANES>| {
+        let (best, best_idxs) = *acc;
+        let is_unset = best_idxs.simd_eq(unset).cast() & T::simd_eq(values, values);
+        let take = (better(values, best) | is_unset) & enable.cast();
+        *acc = (
+            take.select(values, best),
+            take.cast()
+                .select(lane_indices() + Simd::splat(start), best_idxs),
+        );
+    };
+
+    let mut accs = [(Simd::splat(T::ZERO), unset); 4];
+    for_each_block::<_, LANES>(
+        slice.len(),
+        &mut accs,
+        |acc, start| {
+            update(
+                acc,
+                Simd::from_slice(&slice[start..]),
+                start,
+                Mask::splat(true),
+            )
+        },
+        |acc, start| {
+            let enable = enable(slice.len() - start);
+            update(
+                acc,
+                load_or(&slice[start..], enable, T::ZERO),
+                start,
+                enable,
+            );
+        },
+    );
+
+    // Ties between lanes go to the lowest index
+    let mut position: Option<(T, usize)> = None;
+    for (best, best_idxs) in accs {
+        for (value, idx) in best.to_array().into_iter().zip(best_idxs.to_array()) {
+            let take = match position {
+                _ if idx == usize::MAX => false,
+                None => true,
+                Some((best, best_idx)) => {
+                    better(Simd::splat(value), Simd::splat(best)).test(0)
+                        || (value == best && idx < best_idx)
+                }
+            };
+            if take {
+                position = Some((value, idx));
+            }
+        }
+    }
+    position.map(|(_, idx)| idx)
+}
+
+/// Returns the index of the first minimum element of `slice`, comparing `LANES` elements at a
+/// time, or `None` if it is empty.
+///
+/// NaNs are ignored. If every element is NaN, the result is `None`.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// assert_eq!(slice::argmin::<_, 4>(&[3., f32::NAN, -1., 7., -1.]), Some(2));
+/// assert_eq!(slice::argmin::<_, 4>(&[f32::NAN]), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn argmin<T, const LANES: usize>(slice: &[T]) -> Option<usize>
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    position_by::<T, LANES>(slice, T::simd_lt)
+}
+
+/// Returns the index of the first maximum element of `slice`, comparing `LANES` elements at a
+/// time, or `None` if it is empty.
+///
+/// NaNs are ignored. If every element is NaN, the result is `None`.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// assert_eq!(slice::argmax::<_, 4>(&[3., f32::NAN, 7., -1., 7.]), Some(2));
+/// assert_eq!(slice::argmax::<u8, 4>(&[]), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn argmax<T, const LANES: usize>(slice: &[T]) -> Option<usize>
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    position_by::<T, LANES>(slice, T::simd_gt)
+}
+
+/// Returns the number of elements of `slice` that are equal to `value`, comparing `LANES`
+/// elements at a time.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// assert_eq!(slice::count_eq::<_, 4>(b"mississippi", b's'), 4);
+/// assert_eq!(slice::count_eq::<_, 4>(&[f32::NAN], f32::NAN), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn count_eq<T, const LANES: usize>(slice: &[T], value: T) -> usize
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let value = Simd::splat(value);
+    // Equal lanes are -1 as integers
+    let mut accs = [Simd::<isize, LANES>::splat(0); 4];
+    for_each_block::<_, LANES>(
+        slice.len(),
+        &mut accs,
+        |acc, start| {
+            *acc -= T::simd_eq(Simd::from_slice(&slice[start..]), value)
+                .cast::<isize>()
+                .to_int()
+        },
+        |acc, start| {
+            let tail = &slice[start..];
+            let enable = enable(tail.len());
+            *acc -= (T::simd_eq(load_or(tail, enable, T::ZERO), value).cast() & enable).to_int();
+        },
+    );
+    let [a, b, c, d] = accs;
+    (a + b + c + d).reduce_sum() as usize
+}
+
+/// Applies `op` to each block of `LANES` elements of `x`, and the same elements of `y`, and
+/// stores the results in `y`.
+#[inline]
+fn zip_with<T, const LANES: usize>(
+    x: &[T],
+    y: &mut [T],
+    op: impl Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+) where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut xs = x.chunks_exact(LANES);
+    let mut ys = y.chunks_exact_mut(LANES);
+    for (x, y) in xs.by_ref().zip(ys.by_ref()) {
+        let result = op(Simd::from_slice(x), Simd::from_slice(y));
+        y.copy_from_slice(result.as_array());
+    }
+
+    let (x, y) = (xs.remainder(), ys.into_remainder());
+    if !y.is_empty() {
+        let enable = enable(y.len());
+        let result = op(load_or(x, enable, T::ZERO), load_or(y, enable, T::ZERO));
+        result.scatter_select(y, enable, lane_indices());
+    }
+}
+
+/// Multiplies each element of `x` by `a` and adds it to the same element of `y`, `LANES`
+/// elements at a time.
+///
+/// Each product is rounded before it is added. Integer sums and products wrap on overflow.
+///
+/// # Panics
+/// Panics if `x` and `y` have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// let x = [1., 2., 3., 4., 5.];
+/// let mut y = [10., 20., 30., 40., 50.];
+/// slice::axpy::<_, 4>(2., &x, &mut y);
+/// assert_eq!(y, [12., 24., 36., 48., 60.]);
+/// ```
+#[inline]
+pub fn axpy<T, const LANES: usize>(a: T, x: &[T], y: &mut [T])
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert_eq!(x.len(), y.len(), "slices must have the same length");
+    let a = Simd::splat(a);
+    zip_with::<T, LANES>(x, y, |x, y| T::simd_add(T::simd_mul(a, x), y));
+}
+
+/// Multiplies each element of `x` by `a`, `LANES` elements at a time.
+///
+/// Integer products wrap on overflow.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// let mut x = [1, 2, 3, 4, 5];
+/// slice::scale::<_, 4>(3, &mut x);
+/// assert_eq!(x, [3, 6, 9, 12, 15]);
+/// ```
+#[inline]
+pub fn scale<T, const LANES: usize>(a: T, x: &mut [T])
+where
+    T: SliceElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let a = Simd::splat(a);
+    let mut xs = x.chunks_exact_mut(LANES);
+    for x in xs.by_ref() {
+        x.copy_from_slice(T::simd_mul(a, Simd::from_slice(x)).as_array());
+    }
+
+    let x = xs.into_remainder();
+    if !x.is_empty() {
+        let enable = enable(x.len());
+        T::simd_mul(a, load_or(x, enable, T::ZERO)).scatter_select(x, enable, lane_indices());
+    }
+}
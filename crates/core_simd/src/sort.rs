@@ -0,0 +1,430 @@
+use crate::simd::{
+    LaneCount, Mask, Simd, SimdElement, SimdOrd, SimdPartialEq, SimdPartialOrd, SupportedLaneCount,
+    Swizzle,
+};
+
+/// Pairs each lane with the lane whose index differs only in the bit `DISTANCE`.
+struct Butterfly<const DISTANCE: usize>;
+
+const fn butterfly_index<const DISTANCE: usize, const LANES: usize>() -> [usize; LANES] {
+    let mut index = [0; LANES];
+    let mut i = 0;
+    while i < LANES {
+        // Distances of at least `LANES` are never used, but must still be valid swizzles
+        index[i] = if i ^ DISTANCE < LANES {
+            i ^ DISTANCE
+        } else {
+            i
+        };
+        i += 1;
+    }
+    index
+}
+
+impl<const DISTANCE: usize, const LANES: usize> Swizzle<LANES, LANES> for Butterfly<DISTANCE> {
+    const INDEX: [usize; LANES] = butterfly_index::<DISTANCE, LANES>();
+}
+
+/// Pairs each even lane with the next lane, or each odd lane with the next lane if `ODD`.
+struct Neighbor<const ODD: bool>;
+
+const fn neighbor_index<const ODD: bool, const LANES: usize>() -> [usize; LANES] {
+    let mut index = [0; LANES];
+    let mut i = 0;
+    while i < LANES {
+        index[i] = if i % 2 == ODD as usize {
+            if i + 1 < LANES {
+                i + 1
+            } else {
+                i
+            }
+        } else if i > 0 {
+            i - 1
+        } else {
+            i
+        };
+        i += 1;
+    }
+    index
+}
+
+impl<const ODD: bool, const LANES: usize> Swizzle<LANES, LANES> for Neighbor<ODD> {
+    const INDEX: [usize; LANES] = neighbor_index::<ODD, LANES>();
+}
+
+/// Vectors that can be reordered by a sorting network.
+trait Sortable<const LANES: usize>: Copy {
+    /// Compares each lane to the lane selected by `S`, and exchanges them if they are out of
+    /// order. The smaller key is kept in the lanes where `lower` is true.
+    fn exchange<S: Swizzle<LANES, LANES>>(self, lower: [bool; LANES]) -> Self;
+}
+
+impl<T, const LANES: usize> Sortable<LANES> for Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+    Self: SimdOrd,
+{
+    #[inline]
+    fn exchange<S: Swizzle<LANES, LANES>>(self, lower: [bool; LANES]) -> Self {
+        let partner = S::swizzle(self);
+        Mask::<T::Mask, LANES>::from_array(lower)
+            .select(self.simd_min(partner), self.simd_max(partner))
+    }
+}
+
+// Keys with a payload that follows them
+impl<T, U, const LANES: usize> Sortable<LANES> for (Simd<T, LANES>, Simd<U, LANES>)
+where
+    T: SimdElement,
+    U: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: SimdOrd + SimdPartialEq<Mask = Mask<T::Mask, LANES>>,
+{
+    #[inline]
+    fn exchange<S: Swizzle<LANES, LANES>>(self, lower: [bool; LANES]) -> Self {
+        let (keys, payload) = self;
+        let partner = S::swizzle(keys);
+        let take_partner = Mask::<T::Mask, LANES>::from_array(lower)
+            .select_mask(partner.simd_lt(keys), partner.simd_gt(keys));
+        (
+            take_partner.select(partner, keys),
+            take_partner
+                .cast::<U::Mask>()
+                .select(S::swizzle(payload), payload),
+        )
+    }
+}
+
+/// Exchanges lanes `distance` apart, where `distance` is a power of two less than `LANES`.
+#[inline]
+fn exchange_butterfly<V, const LANES: usize>(v: V, distance: usize, lower: [bool; LANES]) -> V
+where
+    V: Sortable<LANES>,
+{
+    match distance {
+        1 => v.exchange::<Butterfly<1>>(lower),
+        2 => v.exchange::<Butterfly<2>>(lower),
+        4 => v.exchange::<Butterfly<4>>(lower),
+        8 => v.exchange::<Butterfly<8>>(lower),
+        16 => v.exchange::<Butterfly<16>>(lower),
+        32 => v.exchange::<Butterfly<32>>(lower),
+        64 => v.exchange::<Butterfly<64>>(lower),
+        128 => v.exchange::<Butterfly<128>>(lower),
+        _ => unreachable!("lane counts are at most 256"),
+    }
+}
+
+/// Sorts bitonic sequences of `block` lanes, alternating between ascending and descending
+/// blocks. `LANES` must be a power of two.
+#[inline]
+fn bitonic_merge<V, const LANES: usize>(mut v: V, block: usize) -> V
+where
+    V: Sortable<LANES>,
+{
+    let mut distance = block / 2;
+    while distance > 0 {
+        let lower = core::array::from_fn(|i| (i & distance == 0) == (i & block == 0));
+        v = exchange_butterfly(v, distance, lower);
+        distance /= 2;
+    }
+    v
+}
+
+/// Sorts the lanes in ascending order.
+///
+/// Power-of-two lane counts use a bitonic sorting network, which takes `log2(LANES)` merges.
+/// Other lane counts use an odd-even transposition network, which takes `LANES` rounds.
+#[inline]
+fn sort_network<V, const LANES: usize>(mut v: V) -> V
+where
+    V: Sortable<LANES>,
+{
+    if LANES.is_power_of_two() {
+        let mut block = 2;
+        while block <= LANES {
+            v = bitonic_merge(v, block);
+            block *= 2;
+        }
+    } else {
+        for round in 0..LANES {
+            let odd = round % 2 == 1;
+            let lower = core::array::from_fn(|i| i % 2 == odd as usize);
+            v = if odd {
+                v.exchange::<Neighbor<true>>(lower)
+            } else {
+                v.exchange::<Neighbor<false>>(lower)
+            };
+        }
+    }
+    v
+}
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+    Self: SimdOrd,
+{
+    /// Sorts the lanes of the vector in ascending order.
+    ///
+    /// The lanes are sorted with a sorting network of [`SimdOrd::simd_min`],
+    /// [`SimdOrd::simd_max`] and swizzles, so the time taken doesn't depend on the values.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::i32x8;
+    /// let v = i32x8::from_array([5, -1, 7, 3, 3, 0, 9, -4]);
+    /// assert_eq!(v.sort_lanes().to_array(), [-4, -1, 0, 3, 3, 5, 7, 9]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    pub fn sort_lanes(self) -> Self {
+        sort_network(self)
+    }
+
+    /// Sorts the lanes of the vector in ascending order, moving the lanes of `payload` along
+    /// with them.
+    ///
+    /// The sort is not stable: the payloads of equal keys may be in any order.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{u32x4, f32x4};
+    /// let keys = u32x4::from_array([30, 10, 40, 20]);
+    /// let values = f32x4::from_array([3., 1., 4., 2.]);
+    /// let (keys, values) = keys.sort_lanes_by_key(values);
+    /// assert_eq!(keys.to_array(), [10, 20, 30, 40]);
+    /// assert_eq!(values.to_array(), [1., 2., 3., 4.]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns new vectors and does not mutate the original values"]
+    pub fn sort_lanes_by_key<U>(self, payload: Simd<U, LANES>) -> (Self, Simd<U, LANES>)
+    where
+        U: SimdElement,
+        Self: SimdPartialEq<Mask = Mask<T::Mask, LANES>>,
+    {
+        sort_network((self, payload))
+    }
+
+    /// Merges two sorted vectors.
+    ///
+    /// If the lanes of `a` and `b` are each in ascending order, returns the lowest `LANES` and
+    /// the highest `LANES` of their lanes, both in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::u8x4;
+    /// let a = u8x4::from_array([1, 4, 6, 7]);
+    /// let b = u8x4::from_array([2, 3, 5, 8]);
+    /// let (lo, hi) = u8x4::merge_sorted(a, b);
+    /// assert_eq!(lo.to_array(), [1, 2, 3, 4]);
+    /// assert_eq!(hi.to_array(), [5, 6, 7, 8]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns new vectors and does not mutate the original values"]
+    pub fn merge_sorted(a: Self, b: Self) -> (Self, Self) {
+        // Comparing `a` to `b` reversed splits the lanes into two halves, which are each
+        // bitonic: ascending, then descending.
+        let b = b.reverse();
+        let (lo, hi) = (a.simd_min(b), a.simd_max(b));
+        if LANES.is_power_of_two() {
+            (bitonic_merge(lo, LANES), bitonic_merge(hi, LANES))
+        } else {
+            (lo.sort_lanes(), hi.sort_lanes())
+        }
+    }
+}
+
+/// Sorts a slice in ascending order, using SIMD vectors with `LANES` lanes.
+///
+/// In the spirit of vqsort, the slice is partitioned as in quicksort, comparing a vector of
+/// elements at a time against the pivot, and partitions of up to `2 * LANES` elements are
+/// sorted with [`Simd::sort_lanes`] and [`Simd::merge_sorted`]. If partitioning goes badly,
+/// this falls back to [`slice::sort_unstable`], so the worst case is O(n log n).
+///
+/// The sort is not stable.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::simd_sort;
+/// let mut keys: Vec<u32> = (0..1000).map(|x| x * 7919 % 1000).collect();
+/// simd_sort::<_, 16>(&mut keys);
+/// assert!(keys.windows(2).all(|w| w[0] <= w[1]));
+/// ```
+pub fn simd_sort<T, const LANES: usize>(slice: &mut [T])
+where
+    T: SimdElement + Ord,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: SimdOrd + SimdPartialEq<Mask = Mask<T::Mask, LANES>>,
+{
+    // Like introsort, limit the recursion depth to twice that of a balanced partitioning
+    let limit = 2 * (usize::BITS - slice.len().leading_zeros());
+    quicksort::<T, LANES>(slice, limit);
+}
+
+fn quicksort<T, const LANES: usize>(mut slice: &mut [T], mut limit: u32)
+where
+    T: SimdElement + Ord,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: SimdOrd + SimdPartialEq<Mask = Mask<T::Mask, LANES>>,
+{
+    loop {
+        if slice.len() <= 2 * LANES {
+            sort_small::<T, LANES>(slice);
+            return;
+        }
+        if limit == 0 {
+            slice.sort_unstable();
+            return;
+        }
+        limit -= 1;
+
+        let pivot = median_of_three(slice);
+        let split = partition::<T, LANES>(slice, pivot, false);
+        if split == 0 {
+            // The pivot is the smallest element, so the elements equal to it are in place once
+            // they are moved to the start
+            let split = partition::<T, LANES>(slice, pivot, true);
+            slice = &mut core::mem::take(&mut slice)[split..];
+            continue;
+        }
+        let (left, right) = core::mem::take(&mut slice).split_at_mut(split);
+
+        // Recurse into the smaller partition to bound the stack depth
+        if left.len() < right.len() {
+            quicksort::<T, LANES>(left, limit);
+            slice = right;
+        } else {
+            quicksort::<T, LANES>(right, limit);
+            slice = left;
+        }
+    }
+}
+
+/// Returns the median of the first, middle and last elements of the slice.
+fn median_of_three<T: Copy + Ord>(slice: &[T]) -> T {
+    let (a, b, c) = (slice[0], slice[slice.len() / 2], slice[slice.len() - 1]);
+    a.max(b).min(a.min(b).max(c))
+}
+
+/// Moves the elements less than `pivot`, or also those equal to it if `or_equal`, to the start
+/// of the slice, and returns their number. The slice must have at least `2 * LANES` elements.
+///
+/// Vectors are read from both ends of the unread part of the slice, compared against the pivot,
+/// and the lanes are written one at a time to the written part at the left or the right end.
+/// The first and last vectors are only written at the end, which leaves room at both ends to
+/// write every vector that is read.
+fn partition<T, const LANES: usize>(slice: &mut [T], pivot: T, or_equal: bool) -> usize
+where
+    T: SimdElement + Ord,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: SimdOrd + SimdPartialEq<Mask = Mask<T::Mask, LANES>>,
+{
+    let len = slice.len();
+    let pivots = Simd::splat(pivot);
+    let first = Simd::from_slice(slice);
+    let last = Simd::from_slice(&slice[len - LANES..]);
+
+    // Elements are read from `read_left..read_right`, and written to `..written.left` and
+    // `written.right..`, which together always leave room for `2 * LANES` elements
+    let (mut read_left, mut read_right) = (LANES, len - LANES);
+    let mut written = Written {
+        left: 0,
+        right: len,
+    };
+    let write_vector = |slice: &mut [T], written: &mut Written, v: Simd<T, LANES>| {
+        let left = if or_equal {
+            v.simd_le(pivots)
+        } else {
+            v.simd_lt(pivots)
+        };
+        for (x, left) in v.to_array().into_iter().zip(left.to_array()) {
+            written.push(slice, x, left);
+        }
+    };
+
+    while read_right - read_left >= LANES {
+        // Read from the end with less room, so that both ends have room for a whole vector
+        let v = if read_left - written.left <= written.right - read_right {
+            read_left += LANES;
+            Simd::from_slice(&slice[read_left - LANES..])
+        } else {
+            read_right -= LANES;
+            Simd::from_slice(&slice[read_right..])
+        };
+        write_vector(slice, &mut written, v);
+    }
+    while read_left < read_right {
+        let x = if read_left - written.left <= written.right - read_right {
+            read_left += 1;
+            slice[read_left - 1]
+        } else {
+            read_right -= 1;
+            slice[read_right]
+        };
+        written.push(slice, x, x < pivot || (or_equal && x == pivot));
+    }
+    write_vector(slice, &mut written, first);
+    write_vector(slice, &mut written, last);
+    written.left
+}
+
+/// The ends of the written parts of a slice being partitioned.
+struct Written {
+    left: usize,
+    right: usize,
+}
+
+impl Written {
+    /// Writes an element to the left or the right part, without branching on `left`.
+    ///
+    /// Both parts must have room for the element.
+    #[inline]
+    fn push<T: Copy>(&mut self, slice: &mut [T], x: T, left: bool) {
+        slice[self.left] = x;
+        slice[self.right - 1] = x;
+        self.left += left as usize;
+        self.right -= !left as usize;
+    }
+}
+
+/// Sorts a slice of at most `2 * LANES` elements.
+fn sort_small<T, const LANES: usize>(slice: &mut [T])
+where
+    T: SimdElement + Ord,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: SimdOrd,
+{
+    // Pad the vectors with the largest element, so the padding sorts to the end
+    let max = match slice.iter().max() {
+        Some(&max) => max,
+        None => return,
+    };
+    let load = |elements: &[T]| {
+        let mut array = [max; LANES];
+        array[..elements.len()].copy_from_slice(elements);
+        Simd::from_array(array).sort_lanes()
+    };
+
+    let (first, second) = slice.split_at_mut(slice.len().min(LANES));
+    if second.is_empty() {
+        first.copy_from_slice(&load(first).as_array()[..first.len()]);
+    } else {
+        let (lo, hi) = Simd::merge_sorted(load(first), load(second));
+        first.copy_from_slice(lo.as_array());
+        second.copy_from_slice(&hi.as_array()[..second.len()]);
+    }
+}
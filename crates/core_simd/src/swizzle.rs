@@ -1,8 +1,10 @@
 use crate::simd::intrinsics;
-use crate::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+use crate::simd::{LaneCount, Mask, MaskElement, Simd, SimdElement, SupportedLaneCount};
 
 /// Constructs a new SIMD vector by copying elements from selected lanes in other vectors.
 ///
+/// Masks can be swizzled in the same way as vectors.
+///
 /// When swizzling one vector, lanes are selected by a `const` array of `usize`,
 /// like [`Swizzle`].
 ///
@@ -53,7 +55,7 @@ pub macro simd_swizzle {
             impl<const LANES: usize> Swizzle<LANES, {$index.len()}> for Impl {
                 const INDEX: [usize; {$index.len()}] = $index;
             }
-            Impl::swizzle($vector)
+            $crate::simd::Swizzleable::swizzle_with::<Impl>($vector)
         }
     },
     (
@@ -65,7 +67,7 @@ pub macro simd_swizzle {
             impl<const LANES: usize> Swizzle2<LANES, {$index.len()}> for Impl {
                 const INDEX: [Which; {$index.len()}] = $index;
             }
-            Impl::swizzle2($first, $second)
+            $crate::simd::Swizzleable::swizzle2_with::<Impl>($first, $second)
         }
     }
 }
@@ -100,6 +102,21 @@ pub trait Swizzle<const INPUT_LANES: usize, const OUTPUT_LANES: usize> {
         // Safety: `vector` is a vector, and `INDEX_IMPL` is a const array of u32.
         unsafe { intrinsics::simd_shuffle(vector, vector, Self::INDEX_IMPL) }
     }
+
+    /// Create a new mask from the lanes of `mask`.
+    ///
+    /// Lane `i` of the output is `mask.test(Self::INDEX[i])`.
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original inputs"]
+    fn swizzle_mask<T>(mask: Mask<T, INPUT_LANES>) -> Mask<T, OUTPUT_LANES>
+    where
+        T: MaskElement,
+        LaneCount<INPUT_LANES>: SupportedLaneCount,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+        Self: Sized,
+    {
+        mask.swizzle_impl::<Self, OUTPUT_LANES>()
+    }
 }
 
 /// Create a vector from the elements of two other vectors.
@@ -125,11 +142,85 @@ pub trait Swizzle2<const INPUT_LANES: usize, const OUTPUT_LANES: usize> {
         // Safety: `first` and `second` are vectors, and `INDEX_IMPL` is a const array of u32.
         unsafe { intrinsics::simd_shuffle(first, second, Self::INDEX_IMPL) }
     }
+
+    /// Create a new mask from the lanes of `first` and `second`.
+    ///
+    /// Lane `i` is `first.test(j)` when `Self::INDEX[i]` is `First(j)`, or `second.test(j)` when
+    /// it is `Second(j)`.
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original inputs"]
+    fn swizzle2_mask<T>(
+        first: Mask<T, INPUT_LANES>,
+        second: Mask<T, INPUT_LANES>,
+    ) -> Mask<T, OUTPUT_LANES>
+    where
+        T: MaskElement,
+        LaneCount<INPUT_LANES>: SupportedLaneCount,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+        Self: Sized,
+    {
+        first.swizzle2_impl::<Self, OUTPUT_LANES>(second)
+    }
+}
+
+/// Vectors and masks, which can be swizzled with [`simd_swizzle`].
+///
+/// This trait is an implementation detail of `simd_swizzle`.
+#[doc(hidden)]
+pub trait Swizzleable<const INPUT_LANES: usize, const OUTPUT_LANES: usize> {
+    /// The swizzled type.
+    type Output;
+
+    /// Swizzles `self` with `S`.
+    fn swizzle_with<S: Swizzle<INPUT_LANES, OUTPUT_LANES>>(self) -> Self::Output;
+
+    /// Swizzles `self` and `second` with `S`.
+    fn swizzle2_with<S: Swizzle2<INPUT_LANES, OUTPUT_LANES>>(self, second: Self) -> Self::Output;
+}
+
+impl<T, const INPUT_LANES: usize, const OUTPUT_LANES: usize> Swizzleable<INPUT_LANES, OUTPUT_LANES>
+    for Simd<T, INPUT_LANES>
+where
+    T: SimdElement,
+    LaneCount<INPUT_LANES>: SupportedLaneCount,
+    LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+{
+    type Output = Simd<T, OUTPUT_LANES>;
+
+    #[inline]
+    fn swizzle_with<S: Swizzle<INPUT_LANES, OUTPUT_LANES>>(self) -> Self::Output {
+        S::swizzle(self)
+    }
+
+    #[inline]
+    fn swizzle2_with<S: Swizzle2<INPUT_LANES, OUTPUT_LANES>>(self, second: Self) -> Self::Output {
+        S::swizzle2(self, second)
+    }
+}
+
+impl<T, const INPUT_LANES: usize, const OUTPUT_LANES: usize> Swizzleable<INPUT_LANES, OUTPUT_LANES>
+    for Mask<T, INPUT_LANES>
+where
+    T: MaskElement,
+    LaneCount<INPUT_LANES>: SupportedLaneCount,
+    LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+{
+    type Output = Mask<T, OUTPUT_LANES>;
+
+    #[inline]
+    fn swizzle_with<S: Swizzle<INPUT_LANES, OUTPUT_LANES>>(self) -> Self::Output {
+        S::swizzle_mask(self)
+    }
+
+    #[inline]
+    fn swizzle2_with<S: Swizzle2<INPUT_LANES, OUTPUT_LANES>>(self, second: Self) -> Self::Output {
+        S::swizzle2_mask(self, second)
+    }
 }
 
 /// The `simd_shuffle` intrinsic expects `u32`, so do error checking and conversion here.
 /// This trait hides `INDEX_IMPL` from the public API.
-trait SwizzleImpl<const INPUT_LANES: usize, const OUTPUT_LANES: usize> {
+pub(crate) trait SwizzleImpl<const INPUT_LANES: usize, const OUTPUT_LANES: usize> {
     const INDEX_IMPL: [u32; OUTPUT_LANES];
 }
 
@@ -154,7 +245,7 @@ where
 
 /// The `simd_shuffle` intrinsic expects `u32`, so do error checking and conversion here.
 /// This trait hides `INDEX_IMPL` from the public API.
-trait Swizzle2Impl<const INPUT_LANES: usize, const OUTPUT_LANES: usize> {
+pub(crate) trait Swizzle2Impl<const INPUT_LANES: usize, const OUTPUT_LANES: usize> {
     const INDEX_IMPL: [u32; OUTPUT_LANES];
 }
 
@@ -183,32 +274,333 @@ where
     };
 }
 
-impl<T, const LANES: usize> Simd<T, LANES>
+/// Swizzles `vector` with `S`, like [`Swizzle::swizzle`], but also callable in const contexts.
+///
+/// `simd_shuffle` can't be evaluated by the compiler, so in const contexts the lanes are copied
+/// one at a time instead.
+#[inline]
+pub(crate) const fn const_swizzle<S, T, const INPUT_LANES: usize, const OUTPUT_LANES: usize>(
+    vector: Simd<T, INPUT_LANES>,
+) -> Simd<T, OUTPUT_LANES>
 where
+    S: Swizzle<INPUT_LANES, OUTPUT_LANES>,
     T: SimdElement,
-    LaneCount<LANES>: SupportedLaneCount,
+    LaneCount<INPUT_LANES>: SupportedLaneCount,
+    LaneCount<OUTPUT_LANES>: SupportedLaneCount,
 {
-    /// Reverse the order of the lanes in the vector.
-    #[inline]
-    #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn reverse(self) -> Self {
-        const fn reverse_index<const LANES: usize>() -> [usize; LANES] {
-            let mut index = [0; LANES];
-            let mut i = 0;
-            while i < LANES {
-                index[i] = LANES - i - 1;
-                i += 1;
-            }
-            index
+    const fn swizzle_const<S, T, const INPUT_LANES: usize, const OUTPUT_LANES: usize>(
+        vector: Simd<T, INPUT_LANES>,
+    ) -> Simd<T, OUTPUT_LANES>
+    where
+        S: Swizzle<INPUT_LANES, OUTPUT_LANES>,
+        T: SimdElement,
+        LaneCount<INPUT_LANES>: SupportedLaneCount,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        let input = vector.to_array();
+        let mut output = [input[0]; OUTPUT_LANES];
+        let mut i = 0;
+        while i < OUTPUT_LANES {
+            let index = <S as SwizzleImpl<INPUT_LANES, OUTPUT_LANES>>::INDEX_IMPL[i] as usize;
+            output[i] = input[index];
+            i += 1;
         }
+        Simd::from_array(output)
+    }
 
-        struct Reverse;
+    fn swizzle_rt<S, T, const INPUT_LANES: usize, const OUTPUT_LANES: usize>(
+        vector: Simd<T, INPUT_LANES>,
+    ) -> Simd<T, OUTPUT_LANES>
+    where
+        S: Swizzle<INPUT_LANES, OUTPUT_LANES>,
+        T: SimdElement,
+        LaneCount<INPUT_LANES>: SupportedLaneCount,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        S::swizzle(vector)
+    }
 
-        impl<const LANES: usize> Swizzle<LANES, LANES> for Reverse {
-            const INDEX: [usize; LANES] = reverse_index::<LANES>();
+    // Safety: both functions select the same lanes
+    unsafe {
+        core::intrinsics::const_eval_select(
+            (vector,),
+            swizzle_const::<S, T, INPUT_LANES, OUTPUT_LANES>,
+            swizzle_rt::<S, T, INPUT_LANES, OUTPUT_LANES>,
+        )
+    }
+}
+
+/// Swizzles `first` and `second` with `S`, like [`Swizzle2::swizzle2`], but also callable in
+/// const contexts.
+#[inline]
+pub(crate) const fn const_swizzle2<S, T, const INPUT_LANES: usize, const OUTPUT_LANES: usize>(
+    first: Simd<T, INPUT_LANES>,
+    second: Simd<T, INPUT_LANES>,
+) -> Simd<T, OUTPUT_LANES>
+where
+    S: Swizzle2<INPUT_LANES, OUTPUT_LANES>,
+    T: SimdElement,
+    LaneCount<INPUT_LANES>: SupportedLaneCount,
+    LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+{
+    const fn swizzle2_const<S, T, const INPUT_LANES: usize, const OUTPUT_LANES: usize>(
+        first: Simd<T, INPUT_LANES>,
+        second: Simd<T, INPUT_LANES>,
+    ) -> Simd<T, OUTPUT_LANES>
+    where
+        S: Swizzle2<INPUT_LANES, OUTPUT_LANES>,
+        T: SimdElement,
+        LaneCount<INPUT_LANES>: SupportedLaneCount,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        let first = first.to_array();
+        let second = second.to_array();
+        let mut output = [first[0]; OUTPUT_LANES];
+        let mut i = 0;
+        while i < OUTPUT_LANES {
+            // lanes are indexed by the first vector, then second vector
+            let index = <S as Swizzle2Impl<INPUT_LANES, OUTPUT_LANES>>::INDEX_IMPL[i] as usize;
+            output[i] = if index < INPUT_LANES {
+                first[index]
+            } else {
+                second[index - INPUT_LANES]
+            };
+            i += 1;
         }
+        Simd::from_array(output)
+    }
 
-        Reverse::swizzle(self)
+    fn swizzle2_rt<S, T, const INPUT_LANES: usize, const OUTPUT_LANES: usize>(
+        first: Simd<T, INPUT_LANES>,
+        second: Simd<T, INPUT_LANES>,
+    ) -> Simd<T, OUTPUT_LANES>
+    where
+        S: Swizzle2<INPUT_LANES, OUTPUT_LANES>,
+        T: SimdElement,
+        LaneCount<INPUT_LANES>: SupportedLaneCount,
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        S::swizzle2(first, second)
+    }
+
+    // Safety: both functions select the same lanes
+    unsafe {
+        core::intrinsics::const_eval_select(
+            (first, second),
+            swizzle2_const::<S, T, INPUT_LANES, OUTPUT_LANES>,
+            swizzle2_rt::<S, T, INPUT_LANES, OUTPUT_LANES>,
+        )
+    }
+}
+
+// Lane indices of the swizzles implemented by both vectors and masks.
+
+const fn reverse_index<const LANES: usize>() -> [usize; LANES] {
+    let mut index = [0; LANES];
+    let mut i = 0;
+    while i < LANES {
+        index[i] = LANES - i - 1;
+        i += 1;
+    }
+    index
+}
+
+struct Reverse;
+
+impl<const LANES: usize> Swizzle<LANES, LANES> for Reverse {
+    const INDEX: [usize; LANES] = reverse_index::<LANES>();
+}
+
+const fn rotate_left_index<const OFFSET: usize, const LANES: usize>() -> [usize; LANES] {
+    let offset = OFFSET % LANES;
+    let mut index = [0; LANES];
+    let mut i = 0;
+    while i < LANES {
+        index[i] = (i + offset) % LANES;
+        i += 1;
+    }
+    index
+}
+
+struct RotateLeft<const OFFSET: usize>;
+
+impl<const OFFSET: usize, const LANES: usize> Swizzle<LANES, LANES> for RotateLeft<OFFSET> {
+    const INDEX: [usize; LANES] = rotate_left_index::<OFFSET, LANES>();
+}
+
+const fn rotate_right_index<const OFFSET: usize, const LANES: usize>() -> [usize; LANES] {
+    let offset = LANES - OFFSET % LANES;
+    let mut index = [0; LANES];
+    let mut i = 0;
+    while i < LANES {
+        index[i] = (i + offset) % LANES;
+        i += 1;
+    }
+    index
+}
+
+struct RotateRight<const OFFSET: usize>;
+
+impl<const OFFSET: usize, const LANES: usize> Swizzle<LANES, LANES> for RotateRight<OFFSET> {
+    const INDEX: [usize; LANES] = rotate_right_index::<OFFSET, LANES>();
+}
+
+const fn interleave_index<const LANES: usize>(high: bool) -> [Which; LANES] {
+    let mut idx = [Which::First(0); LANES];
+    let mut i = 0;
+    while i < LANES {
+        // Treat the source as a concatenated vector
+        let dst_index = if high { i + LANES } else { i };
+        let src_index = dst_index / 2 + (dst_index % 2) * LANES;
+        idx[i] = if src_index < LANES {
+            Which::First(src_index)
+        } else {
+            Which::Second(src_index % LANES)
+        };
+        i += 1;
+    }
+    idx
+}
+
+struct Lo;
+struct Hi;
+
+impl<const LANES: usize> Swizzle2<LANES, LANES> for Lo {
+    const INDEX: [Which; LANES] = interleave_index::<LANES>(false);
+}
+
+impl<const LANES: usize> Swizzle2<LANES, LANES> for Hi {
+    const INDEX: [Which; LANES] = interleave_index::<LANES>(true);
+}
+
+const fn deinterleave_index<const LANES: usize>(second: bool) -> [Which; LANES] {
+    let mut idx = [Which::First(0); LANES];
+    let mut i = 0;
+    while i < LANES {
+        // Treat the source as a concatenated vector
+        let src_index = i * 2 + second as usize;
+        idx[i] = if src_index < LANES {
+            Which::First(src_index)
+        } else {
+            Which::Second(src_index % LANES)
+        };
+        i += 1;
+    }
+    idx
+}
+
+struct Even;
+struct Odd;
+
+impl<const LANES: usize> Swizzle2<LANES, LANES> for Even {
+    const INDEX: [Which; LANES] = deinterleave_index::<LANES>(false);
+}
+
+impl<const LANES: usize> Swizzle2<LANES, LANES> for Odd {
+    const INDEX: [Which; LANES] = deinterleave_index::<LANES>(true);
+}
+
+// Three-way interleaving takes two two-vector swizzles per output: the first takes the lanes
+// from the first two vectors, and the second fills in the rest from the third.
+
+const fn deinterleave3_index<const FIELD: usize, const LAST: bool, const LANES: usize>(
+) -> [Which; LANES] {
+    let mut idx = [Which::First(0); LANES];
+    let mut i = 0;
+    while i < LANES {
+        // Treat the source as three concatenated vectors
+        let src_index = i * 3 + FIELD;
+        idx[i] = if src_index < LANES && !LAST {
+            Which::First(src_index)
+        } else if src_index < 2 * LANES && !LAST {
+            Which::Second(src_index - LANES)
+        } else if src_index >= 2 * LANES && LAST {
+            Which::Second(src_index - 2 * LANES)
+        } else {
+            Which::First(i)
+        };
+        i += 1;
+    }
+    idx
+}
+
+struct Deinterleave3<const FIELD: usize, const LAST: bool>;
+
+impl<const FIELD: usize, const LAST: bool, const LANES: usize> Swizzle2<LANES, LANES>
+    for Deinterleave3<FIELD, LAST>
+{
+    const INDEX: [Which; LANES] = deinterleave3_index::<FIELD, LAST, LANES>();
+}
+
+const fn interleave3_index<const VECTOR: usize, const LAST: bool, const LANES: usize>(
+) -> [Which; LANES] {
+    let mut idx = [Which::First(0); LANES];
+    let mut i = 0;
+    while i < LANES {
+        // Treat the destination as three concatenated vectors
+        let dst_index = VECTOR * LANES + i;
+        let (record, field) = (dst_index / 3, dst_index % 3);
+        idx[i] = match (LAST, field) {
+            (false, 0) => Which::First(record),
+            (false, 1) | (true, 2) => Which::Second(record),
+            _ => Which::First(i),
+        };
+        i += 1;
+    }
+    idx
+}
+
+struct Interleave3<const VECTOR: usize, const LAST: bool>;
+
+impl<const VECTOR: usize, const LAST: bool, const LANES: usize> Swizzle2<LANES, LANES>
+    for Interleave3<VECTOR, LAST>
+{
+    const INDEX: [Which; LANES] = interleave3_index::<VECTOR, LAST, LANES>();
+}
+
+/// Splits three vectors, holding records of three elements each, into one vector per field.
+#[inline]
+pub(crate) fn deinterleave3<T, const LANES: usize>(
+    vectors: [Simd<T, LANES>; 3],
+) -> [Simd<T, LANES>; 3]
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let [a, b, c] = vectors;
+    [
+        Deinterleave3::<0, true>::swizzle2(Deinterleave3::<0, false>::swizzle2(a, b), c),
+        Deinterleave3::<1, true>::swizzle2(Deinterleave3::<1, false>::swizzle2(a, b), c),
+        Deinterleave3::<2, true>::swizzle2(Deinterleave3::<2, false>::swizzle2(a, b), c),
+    ]
+}
+
+/// Combines three vectors, one per field, into three vectors holding records of three
+/// elements each. This is the inverse of [`deinterleave3`].
+#[inline]
+pub(crate) fn interleave3<T, const LANES: usize>(fields: [Simd<T, LANES>; 3]) -> [Simd<T, LANES>; 3]
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let [x, y, z] = fields;
+    [
+        Interleave3::<0, true>::swizzle2(Interleave3::<0, false>::swizzle2(x, y), z),
+        Interleave3::<1, true>::swizzle2(Interleave3::<1, false>::swizzle2(x, y), z),
+        Interleave3::<2, true>::swizzle2(Interleave3::<2, false>::swizzle2(x, y), z),
+    ]
+}
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Reverse the order of the lanes in the vector.
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub const fn reverse(self) -> Self {
+        const_swizzle::<Reverse, T, LANES, LANES>(self)
     }
 
     /// Rotates the vector such that the first `OFFSET` elements of the slice move to the end
@@ -216,25 +608,8 @@ where
     /// the element previously in lane `OFFSET` will become the first element in the slice.
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn rotate_lanes_left<const OFFSET: usize>(self) -> Self {
-        const fn rotate_index<const OFFSET: usize, const LANES: usize>() -> [usize; LANES] {
-            let offset = OFFSET % LANES;
-            let mut index = [0; LANES];
-            let mut i = 0;
-            while i < LANES {
-                index[i] = (i + offset) % LANES;
-                i += 1;
-            }
-            index
-        }
-
-        struct Rotate<const OFFSET: usize>;
-
-        impl<const OFFSET: usize, const LANES: usize> Swizzle<LANES, LANES> for Rotate<OFFSET> {
-            const INDEX: [usize; LANES] = rotate_index::<OFFSET, LANES>();
-        }
-
-        Rotate::<OFFSET>::swizzle(self)
+    pub const fn rotate_lanes_left<const OFFSET: usize>(self) -> Self {
+        const_swizzle::<RotateLeft<OFFSET>, T, LANES, LANES>(self)
     }
 
     /// Rotates the vector such that the first `LANES - OFFSET` elements of the vector move to
@@ -242,25 +617,8 @@ where
     /// the element previously at index `LANES - OFFSET` will become the first element in the slice.
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn rotate_lanes_right<const OFFSET: usize>(self) -> Self {
-        const fn rotate_index<const OFFSET: usize, const LANES: usize>() -> [usize; LANES] {
-            let offset = LANES - OFFSET % LANES;
-            let mut index = [0; LANES];
-            let mut i = 0;
-            while i < LANES {
-                index[i] = (i + offset) % LANES;
-                i += 1;
-            }
-            index
-        }
-
-        struct Rotate<const OFFSET: usize>;
-
-        impl<const OFFSET: usize, const LANES: usize> Swizzle<LANES, LANES> for Rotate<OFFSET> {
-            const INDEX: [usize; LANES] = rotate_index::<OFFSET, LANES>();
-        }
-
-        Rotate::<OFFSET>::swizzle(self)
+    pub const fn rotate_lanes_right<const OFFSET: usize>(self) -> Self {
+        const_swizzle::<RotateRight<OFFSET>, T, LANES, LANES>(self)
     }
 
     /// Interleave two vectors.
@@ -281,36 +639,11 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn interleave(self, other: Self) -> (Self, Self) {
-        const fn interleave<const LANES: usize>(high: bool) -> [Which; LANES] {
-            let mut idx = [Which::First(0); LANES];
-            let mut i = 0;
-            while i < LANES {
-                // Treat the source as a concatenated vector
-                let dst_index = if high { i + LANES } else { i };
-                let src_index = dst_index / 2 + (dst_index % 2) * LANES;
-                idx[i] = if src_index < LANES {
-                    Which::First(src_index)
-                } else {
-                    Which::Second(src_index % LANES)
-                };
-                i += 1;
-            }
-            idx
-        }
-
-        struct Lo;
-        struct Hi;
-
-        impl<const LANES: usize> Swizzle2<LANES, LANES> for Lo {
-            const INDEX: [Which; LANES] = interleave::<LANES>(false);
-        }
-
-        impl<const LANES: usize> Swizzle2<LANES, LANES> for Hi {
-            const INDEX: [Which; LANES] = interleave::<LANES>(true);
-        }
-
-        (Lo::swizzle2(self, other), Hi::swizzle2(self, other))
+    pub const fn interleave(self, other: Self) -> (Self, Self) {
+        (
+            const_swizzle2::<Lo, T, LANES, LANES>(self, other),
+            const_swizzle2::<Hi, T, LANES, LANES>(self, other),
+        )
     }
 
     /// Deinterleave two vectors.
@@ -334,34 +667,130 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn deinterleave(self, other: Self) -> (Self, Self) {
-        const fn deinterleave<const LANES: usize>(second: bool) -> [Which; LANES] {
-            let mut idx = [Which::First(0); LANES];
-            let mut i = 0;
-            while i < LANES {
-                // Treat the source as a concatenated vector
-                let src_index = i * 2 + second as usize;
-                idx[i] = if src_index < LANES {
-                    Which::First(src_index)
-                } else {
-                    Which::Second(src_index % LANES)
-                };
-                i += 1;
+    pub const fn deinterleave(self, other: Self) -> (Self, Self) {
+        (
+            const_swizzle2::<Even, T, LANES, LANES>(self, other),
+            const_swizzle2::<Odd, T, LANES, LANES>(self, other),
+        )
+    }
+
+    /// Transpose a square matrix of vectors.
+    ///
+    /// Lane `j` of vector `i` in the result is lane `i` of vector `j` in `rows`.
+    ///
+    /// When `LANES` is a power of two, the transpose is performed in registers with
+    /// `log2(LANES)` rounds of [`Simd::interleave`].
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::f32x4;
+    /// let rows = [
+    ///     f32x4::from_array([0., 1., 2., 3.]),
+    ///     f32x4::from_array([4., 5., 6., 7.]),
+    ///     f32x4::from_array([8., 9., 10., 11.]),
+    ///     f32x4::from_array([12., 13., 14., 15.]),
+    /// ];
+    /// let columns = f32x4::transpose(rows);
+    /// assert_eq!(columns[0].to_array(), [0., 4., 8., 12.]);
+    /// assert_eq!(columns[3].to_array(), [3., 7., 11., 15.]);
+    /// assert_eq!(f32x4::transpose(columns), rows);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new array and does not mutate the original inputs"]
+    pub fn transpose(rows: [Self; LANES]) -> [Self; LANES] {
+        if !LANES.is_power_of_two() {
+            return core::array::from_fn(|i| {
+                Self::from_array(core::array::from_fn(|j| rows[j].to_array()[i]))
+            });
+        }
+
+        // Each round interleaves the top half of the rows with the bottom half, and after
+        // `log2(LANES)` rounds every lane has been moved to its transposed position.
+        let mut rows = rows;
+        for _ in 0..LANES.trailing_zeros() {
+            let mut next = rows;
+            for i in 0..LANES / 2 {
+                let (lo, hi) = rows[i].interleave(rows[i + LANES / 2]);
+                next[2 * i] = lo;
+                next[2 * i + 1] = hi;
             }
-            idx
+            rows = next;
         }
+        rows
+    }
+}
 
-        struct Even;
-        struct Odd;
+impl<T, const LANES: usize> Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Reverse the order of the lanes in the mask.
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original inputs"]
+    pub fn reverse(self) -> Self {
+        Reverse::swizzle_mask(self)
+    }
 
-        impl<const LANES: usize> Swizzle2<LANES, LANES> for Even {
-            const INDEX: [Which; LANES] = deinterleave::<LANES>(false);
-        }
+    /// Rotates the mask such that the first `OFFSET` lanes move to the end while the last
+    /// `LANES - OFFSET` lanes move to the front, like [`Simd::rotate_lanes_left`].
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original inputs"]
+    pub fn rotate_lanes_left<const OFFSET: usize>(self) -> Self {
+        RotateLeft::<OFFSET>::swizzle_mask(self)
+    }
 
-        impl<const LANES: usize> Swizzle2<LANES, LANES> for Odd {
-            const INDEX: [Which; LANES] = deinterleave::<LANES>(true);
-        }
+    /// Rotates the mask such that the first `LANES - OFFSET` lanes move to the end while the
+    /// last `OFFSET` lanes move to the front, like [`Simd::rotate_lanes_right`].
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original inputs"]
+    pub fn rotate_lanes_right<const OFFSET: usize>(self) -> Self {
+        RotateRight::<OFFSET>::swizzle_mask(self)
+    }
 
-        (Even::swizzle2(self, other), Odd::swizzle2(self, other))
+    /// Interleave two masks, like [`Simd::interleave`].
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::mask32x4;
+    /// let a = mask32x4::from_array([true, true, true, true]);
+    /// let b = mask32x4::from_array([false, false, false, false]);
+    /// let (x, y) = a.interleave(b);
+    /// assert_eq!(x.to_array(), [true, false, true, false]);
+    /// assert_eq!(y.to_array(), [true, false, true, false]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original inputs"]
+    pub fn interleave(self, other: Self) -> (Self, Self) {
+        (
+            Lo::swizzle2_mask(self, other),
+            Hi::swizzle2_mask(self, other),
+        )
+    }
+
+    /// Deinterleave two masks, like [`Simd::deinterleave`].
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::mask32x4;
+    /// let a = mask32x4::from_array([true, false, true, false]);
+    /// let b = mask32x4::from_array([true, false, true, false]);
+    /// let (x, y) = a.deinterleave(b);
+    /// assert_eq!(x.to_array(), [true, true, true, true]);
+    /// assert_eq!(y.to_array(), [false, false, false, false]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original inputs"]
+    pub fn deinterleave(self, other: Self) -> (Self, Self) {
+        (
+            Even::swizzle2_mask(self, other),
+            Odd::swizzle2_mask(self, other),
+        )
     }
 }
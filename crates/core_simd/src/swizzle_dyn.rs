@@ -0,0 +1,161 @@
+use crate::simd::{LaneCount, Simd, SupportedLaneCount};
+
+#[cfg(all(target_arch = "x86", target_feature = "ssse3"))]
+use core::arch::x86::_mm_shuffle_epi8;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "ssse3"))]
+use core::arch::x86_64::_mm_shuffle_epi8;
+
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    target_endian = "little"
+))]
+use core::arch::aarch64::vqtbl1q_u8;
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use core::arch::wasm32::i8x16_swizzle;
+
+/// Looks up each index in a 16-byte table with `pshufb`.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "ssse3"
+))]
+#[inline]
+fn swizzle_dyn_16(table: Simd<u8, 16>, idxs: Simd<u8, 16>) -> Option<Simd<u8, 16>> {
+    use crate::simd::SimdPartialOrd;
+
+    // `pshufb` only zeroes lanes with the high bit of the index set
+    let idxs = idxs
+        .simd_lt(Simd::splat(16))
+        .select(idxs, Simd::splat(u8::MAX));
+    // Safety: the target feature is enabled
+    Some(unsafe { _mm_shuffle_epi8(table.into(), idxs.into()) }.into())
+}
+
+/// Looks up each index in a 16-byte table with `tbl`.
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    target_endian = "little"
+))]
+#[inline]
+fn swizzle_dyn_16(table: Simd<u8, 16>, idxs: Simd<u8, 16>) -> Option<Simd<u8, 16>> {
+    // Safety: the target feature is enabled
+    Some(unsafe { vqtbl1q_u8(table.into(), idxs.into()) }.into())
+}
+
+/// Looks up each index in a 16-byte table with `i8x16.swizzle`.
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn swizzle_dyn_16(table: Simd<u8, 16>, idxs: Simd<u8, 16>) -> Option<Simd<u8, 16>> {
+    Some(i8x16_swizzle(table.into(), idxs.into()).into())
+}
+
+/// Targets without a 16-byte table lookup instruction swizzle lane by lane.
+#[cfg(not(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "ssse3"
+    ),
+    all(
+        target_arch = "aarch64",
+        target_feature = "neon",
+        target_endian = "little"
+    ),
+    all(target_arch = "wasm32", target_feature = "simd128"),
+)))]
+#[inline]
+fn swizzle_dyn_16(_table: Simd<u8, 16>, _idxs: Simd<u8, 16>) -> Option<Simd<u8, 16>> {
+    None
+}
+
+impl<const LANES: usize> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Swizzle a vector of bytes according to an index vector that is only known at runtime.
+    ///
+    /// Each lane of the output is the lane of `self` selected by the corresponding lane of
+    /// `idxs`. Indices that are out of bounds select zero.
+    ///
+    /// This is the byte table lookup that algorithms such as UTF-8 validation, base64 decoding
+    /// and nibble-wise popcounts are built on. 16-lane vectors lower to a single instruction on
+    /// targets with SSSE3, AArch64 NEON or WebAssembly SIMD. Other lane counts are swizzled lane
+    /// by lane.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let table = Simd::from_array([10, 11, 12, 13, 14, 15, 16, 17]);
+    /// let idxs = Simd::from_array([7, 0, 3, 3, 8, 255, 1, 6]);
+    /// assert_eq!(table.swizzle_dyn(idxs).to_array(), [17, 10, 13, 13, 0, 0, 11, 16]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    pub fn swizzle_dyn(self, idxs: Self) -> Self {
+        if LANES == 16 {
+            // Safety: the vectors have 16 lanes, so these are no-op conversions
+            let (table, idxs) = unsafe {
+                (
+                    core::mem::transmute_copy::<Self, Simd<u8, 16>>(&self),
+                    core::mem::transmute_copy::<Self, Simd<u8, 16>>(&idxs),
+                )
+            };
+            if let Some(swizzled) = swizzle_dyn_16(table, idxs) {
+                // Safety: as above
+                return unsafe { core::mem::transmute_copy(&swizzled) };
+            }
+        }
+
+        let table = self.to_array();
+        Self::from_array(idxs.to_array().map(|idx| {
+            let idx = usize::from(idx);
+            if idx < LANES {
+                table[idx]
+            } else {
+                0
+            }
+        }))
+    }
+}
+
+impl Simd<u8, 16> {
+    /// Looks up each lane of `idxs` in the 16 lanes of `self`, for any number of lanes.
+    ///
+    /// Each lane of the output is the lane of `self` selected by the corresponding lane of
+    /// `idxs`, and indices that are out of bounds select zero, as in [`Simd::swizzle_dyn`].
+    /// The indices are swizzled 16 lanes at a time, so this takes one instruction per 16 lanes
+    /// on the targets where `swizzle_dyn` does.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{u8x16, u8x4};
+    /// let table = u8x16::from_array(*b"0123456789abcdef");
+    /// let idxs = u8x4::from_array([12, 0, 15, 16]);
+    /// assert_eq!(table.swizzle_dyn_table(idxs).to_array(), [b'c', b'0', b'f', 0]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    pub fn swizzle_dyn_table<const N: usize>(self, idxs: Simd<u8, N>) -> Simd<u8, N>
+    where
+        LaneCount<N>: SupportedLaneCount,
+    {
+        let idxs = idxs.to_array();
+        let mut swizzled = [0; N];
+        for (swizzled, idxs) in swizzled.chunks_mut(16).zip(idxs.chunks(16)) {
+            // Pad the last chunk with out of bounds indices
+            let mut chunk = [u8::MAX; 16];
+            chunk[..idxs.len()].copy_from_slice(idxs);
+            let chunk = self.swizzle_dyn(Self::from_array(chunk));
+            swizzled.copy_from_slice(&chunk.as_array()[..swizzled.len()]);
+        }
+        Simd::from_array(swizzled)
+    }
+}
@@ -1,7 +1,7 @@
 
 use crate::simd::{
-    intrinsics, LaneCount, Mask, MaskElement, SimdCast, SimdCastPtr, SimdConstPtr, SimdMutPtr,
-    SimdPartialOrd, SupportedLaneCount, Swizzle,
+    const_swizzle, deinterleave3, interleave3, intrinsics, LaneCount, Mask, MaskElement, SimdCast,
+    SimdCastPtr, SimdConstPtr, SimdMutPtr, SimdPartialOrd, SupportedLaneCount, Swizzle,
 };
 
 /// A SIMD vector of `LANES` elements of type `T`. `Simd<T, N>` has the same shape as [`[T; N]`](array), but operates like `T`.
@@ -115,14 +115,14 @@ where
     /// let v = u32x4::splat(8);
     /// assert_eq!(v.as_array(), &[8, 8, 8, 8]);
     /// ```
-    pub fn splat(value: T) -> Self {
+    pub const fn splat(value: T) -> Self {
         // This is preferred over `[value; LANES]`, since it's explicitly a splat:
         // https://github.com/rust-lang/rust/issues/97804
         struct Splat;
         impl<const LANES: usize> Swizzle<1, LANES> for Splat {
             const INDEX: [usize; LANES] = [0; LANES];
         }
-        Splat::swizzle(Simd::<T, 1>::from([value]))
+        const_swizzle::<Splat, T, 1, LANES>(Simd::<T, 1>::from_array([value]))
     }
 
     /// Returns an array reference containing the entire SIMD vector.
@@ -181,6 +181,124 @@ where
         unsafe { slice.as_ptr().cast::<Self>().read_unaligned() }
     }
 
+    /// Splits `slice[..K * LANES]`, a sequence of records of `K` elements each, into `K` vectors.
+    /// Lane `i` of vector `k` is `slice[i * K + k]`.
+    ///
+    /// This is the inverse of [`Simd::store_interleaved`], and is commonly used to split packed
+    /// pixels or coordinates into planar vectors.
+    ///
+    /// For `K` up to 4, this loads `K` contiguous vectors and splits them with swizzles, like
+    /// the `vld2`, `vld3` and `vld4` instructions of Arm. Other values of `K` use gathers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is less than `K * LANES`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::u8x4;
+    /// let pixels = [1, 2, 3, 11, 12, 13, 21, 22, 23, 31, 32, 33];
+    /// let [r, g, b] = u8x4::load_interleaved::<3>(&pixels);
+    /// assert_eq!(r.to_array(), [1, 11, 21, 31]);
+    /// assert_eq!(g.to_array(), [2, 12, 22, 32]);
+    /// assert_eq!(b.to_array(), [3, 13, 23, 33]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn load_interleaved<const K: usize>(slice: &[T]) -> [Self; K] {
+        assert!(
+            slice.len() >= K * LANES,
+            "slice length must be at least the number of lanes times `K`"
+        );
+        let load = |k: usize| Self::from_slice(&slice[k * LANES..]);
+        let fields = match K {
+            1 => [load(0); 4],
+            2 => {
+                let (even, odd) = load(0).deinterleave(load(1));
+                [even, odd, even, odd]
+            }
+            3 => {
+                let [x, y, z] = deinterleave3([load(0), load(1), load(2)]);
+                [x, y, z, z]
+            }
+            4 => {
+                // Deinterleaving twice takes every fourth element
+                let (even_lo, odd_lo) = load(0).deinterleave(load(1));
+                let (even_hi, odd_hi) = load(2).deinterleave(load(3));
+                let (x, z) = even_lo.deinterleave(even_hi);
+                let (y, w) = odd_lo.deinterleave(odd_hi);
+                [x, y, z, w]
+            }
+            _ => return core::array::from_fn(|k| Self::gather_strided(slice, k, K)),
+        };
+        core::array::from_fn(|k| fields[k])
+    }
+
+    /// Writes `K` vectors to `slice[..K * LANES]` as a sequence of records of `K` elements each.
+    /// Lane `i` of vector `k` is written to `slice[i * K + k]`.
+    ///
+    /// This is the inverse of [`Simd::load_interleaved`]. For `K` up to 4, the records are
+    /// built with swizzles and stored as `K` contiguous vectors, like the `vst2`, `vst3` and
+    /// `vst4` instructions of Arm. Other values of `K` use scatters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is less than `K * LANES`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::f32x2;
+    /// let x = f32x2::from_array([1., 2.]);
+    /// let y = f32x2::from_array([10., 20.]);
+    /// let z = f32x2::from_array([100., 200.]);
+    /// let mut points = [0.; 6];
+    /// f32x2::store_interleaved([x, y, z], &mut points);
+    /// assert_eq!(points, [1., 10., 100., 2., 20., 200.]);
+    /// ```
+    #[inline]
+    pub fn store_interleaved<const K: usize>(vectors: [Self; K], slice: &mut [T]) {
+        assert!(
+            slice.len() >= K * LANES,
+            "slice length must be at least the number of lanes times `K`"
+        );
+        let field = |k: usize| vectors[k % K];
+        let records = match K {
+            1 => [field(0); 4],
+            2 => {
+                let (lo, hi) = field(0).interleave(field(1));
+                [lo, hi, lo, hi]
+            }
+            3 => {
+                let [a, b, c] = interleave3([field(0), field(1), field(2)]);
+                [a, b, c, c]
+            }
+            4 => {
+                let (even_lo, even_hi) = field(0).interleave(field(2));
+                let (odd_lo, odd_hi) = field(1).interleave(field(3));
+                let (a, b) = even_lo.interleave(odd_lo);
+                let (c, d) = even_hi.interleave(odd_hi);
+                [a, b, c, d]
+            }
+            _ => {
+                for (k, vector) in vectors.into_iter().enumerate() {
+                    vector.scatter_strided(slice, k, K);
+                }
+                return;
+            }
+        };
+        for (k, record) in records.iter().take(K).enumerate() {
+            slice[k * LANES..(k + 1) * LANES].copy_from_slice(record.as_array());
+        }
+    }
+
     /// Performs lanewise conversion of a SIMD vector's elements to another SIMD-valid type.
     ///
     /// This follows the semantics of Rust's `as` conversion for casting
@@ -255,6 +373,9 @@ where
     /// Reads from potentially discontiguous indices in `slice` to construct a SIMD vector.
     /// If an index is out-of-bounds, the lane is instead selected from the `or` vector.
     ///
+    /// The indices may be any [`SimdIndex`] type, such as `usize`, `u32` or `i32`.
+    /// Negative indices are always out-of-bounds.
+    ///
     /// # Examples
     /// ```
     /// # #![feature(portable_simd)]
@@ -268,7 +389,10 @@ where
     /// ```
     #[must_use]
     #[inline]
-    pub fn gather_or(slice: &[T], idxs: Simd<usize, LANES>, or: Self) -> Self {
+    pub fn gather_or<I>(slice: &[T], idxs: Simd<I, LANES>, or: Self) -> Self
+    where
+        I: SimdIndex,
+    {
         Self::gather_select(slice, Mask::splat(true), idxs, or)
     }
 
@@ -287,9 +411,10 @@ where
     /// ```
     #[must_use]
     #[inline]
-    pub fn gather_or_default(slice: &[T], idxs: Simd<usize, LANES>) -> Self
+    pub fn gather_or_default<I>(slice: &[T], idxs: Simd<I, LANES>) -> Self
     where
         T: Default,
+        I: SimdIndex,
     {
         Self::gather_or(slice, idxs, Self::splat(T::default()))
     }
@@ -312,13 +437,16 @@ where
     /// ```
     #[must_use]
     #[inline]
-    pub fn gather_select(
+    pub fn gather_select<I>(
         slice: &[T],
         enable: Mask<isize, LANES>,
-        idxs: Simd<usize, LANES>,
+        idxs: Simd<I, LANES>,
         or: Self,
-    ) -> Self {
-        let enable: Mask<isize, LANES> = enable & idxs.simd_lt(Simd::splat(slice.len()));
+    ) -> Self
+    where
+        I: SimdIndex,
+    {
+        let enable = enable & I::in_bounds(idxs, slice.len());
         // Safety: We have masked-off out-of-bounds lanes.
         unsafe { Self::gather_select_unchecked(slice, enable, idxs, or) }
     }
@@ -353,19 +481,53 @@ where
     #[must_use]
     #[inline]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
-    pub unsafe fn gather_select_unchecked(
+    pub unsafe fn gather_select_unchecked<I>(
         slice: &[T],
         enable: Mask<isize, LANES>,
-        idxs: Simd<usize, LANES>,
+        idxs: Simd<I, LANES>,
         or: Self,
-    ) -> Self {
+    ) -> Self
+    where
+        I: SimdIndex,
+    {
         let base_ptr = Simd::<*const T, LANES>::splat(slice.as_ptr());
         // Ferris forgive me, I have done pointer arithmetic here.
-        let ptrs = base_ptr.wrapping_add(idxs);
+        let ptrs = base_ptr.wrapping_add(idxs.cast::<usize>());
         // Safety: The caller is responsible for determining the indices are okay to read
         unsafe { Self::gather_select_ptr(ptrs, enable, or) }
     }
 
+    /// Reads `LANES` elements from `slice` that are evenly spaced by `stride`, starting at `start`.
+    /// Lane `i` is read from `slice[start + i * stride]`.
+    ///
+    /// This reads a column of a row-major matrix, or a field of an array of structs, without
+    /// building an index vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices are out-of-bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// // A 4x3 row-major matrix
+    /// let matrix = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    /// let column = Simd::<i32, 4>::gather_strided(&matrix, 1, 3);
+    /// assert_eq!(column.to_array(), [1, 4, 7, 10]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn gather_strided(slice: &[T], start: usize, stride: usize) -> Self {
+        let idxs = strided_indices::<LANES>(slice.len(), start, stride);
+        // Safety: The indices have been bounds-checked.
+        unsafe {
+            Self::gather_select_unchecked(slice, Mask::splat(true), idxs, Self::splat(slice[start]))
+        }
+    }
+
     /// Read pointers elementwise into a SIMD vector.
     ///
     /// # Safety
@@ -432,6 +594,10 @@ where
     /// Writes the values in a SIMD vector to potentially discontiguous indices in `slice`.
     /// If two lanes in the scattered vector would write to the same index
     /// only the last lane is guaranteed to actually be written.
+    /// To combine such lanes instead, see [`Simd::scatter_add`].
+    ///
+    /// The indices may be any [`SimdIndex`] type, such as `usize`, `u32` or `i32`.
+    /// Out-of-bounds indices, including negative indices, are not written.
     ///
     /// # Examples
     /// ```
@@ -445,7 +611,10 @@ where
     /// assert_eq!(vec, vec![124, 11, 12, 82, 14, 15, 16, 17, 18]);
     /// ```
     #[inline]
-    pub fn scatter(self, slice: &mut [T], idxs: Simd<usize, LANES>) {
+    pub fn scatter<I>(self, slice: &mut [T], idxs: Simd<I, LANES>)
+    where
+        I: SimdIndex,
+    {
         self.scatter_select(slice, Mask::splat(true), idxs)
     }
 
@@ -470,13 +639,15 @@ where
     /// assert_eq!(vec, vec![-41, 11, 12, 82, 14, 15, 16, 17, 18]);
     /// ```
     #[inline]
-    pub fn scatter_select(
+    pub fn scatter_select<I>(
         self,
         slice: &mut [T],
         enable: Mask<isize, LANES>,
-        idxs: Simd<usize, LANES>,
-    ) {
-        let enable: Mask<isize, LANES> = enable & idxs.simd_lt(Simd::splat(slice.len()));
+        idxs: Simd<I, LANES>,
+    ) where
+        I: SimdIndex,
+    {
+        let enable = enable & I::in_bounds(idxs, slice.len());
         // Safety: We have masked-off out-of-bounds lanes.
         unsafe { self.scatter_select_unchecked(slice, enable, idxs) }
     }
@@ -512,12 +683,14 @@ where
     /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
     #[inline]
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
-    pub unsafe fn scatter_select_unchecked(
+    pub unsafe fn scatter_select_unchecked<I>(
         self,
         slice: &mut [T],
         enable: Mask<isize, LANES>,
-        idxs: Simd<usize, LANES>,
-    ) {
+        idxs: Simd<I, LANES>,
+    ) where
+        I: SimdIndex,
+    {
         // Safety: This block works with *mut T derived from &mut 'a [T],
         // which means it is delicate in Rust's borrowing model, circa 2021:
         // &mut 'a [T] asserts uniqueness, so deriving &'a [T] invalidates live *mut Ts!
@@ -532,13 +705,41 @@ where
             // Now Entering ☢️ *mut T Zone
             let base_ptr = Simd::<*mut T, LANES>::splat(slice.as_mut_ptr());
             // Ferris forgive me, I have done pointer arithmetic here.
-            let ptrs = base_ptr.wrapping_add(idxs);
+            let ptrs = base_ptr.wrapping_add(idxs.cast::<usize>());
             // The ptrs have been bounds-masked to prevent memory-unsafe writes insha'allah
             self.scatter_select_ptr(ptrs, enable);
             // Cleared ☢️ *mut T Zone
         }
     }
 
+    /// Writes the values in a SIMD vector to elements of `slice` that are evenly spaced by
+    /// `stride`, starting at `start`.
+    /// Lane `i` is written to `slice[start + i * stride]`.
+    ///
+    /// If `stride` is zero, only the last lane is guaranteed to actually be written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices are out-of-bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// // A 4x3 row-major matrix
+    /// let mut matrix = [0; 12];
+    /// Simd::from_array([1, 2, 3, 4]).scatter_strided(&mut matrix, 2, 3);
+    /// assert_eq!(matrix, [0, 0, 1, 0, 0, 2, 0, 0, 3, 0, 0, 4]);
+    /// ```
+    #[inline]
+    pub fn scatter_strided(self, slice: &mut [T], start: usize, stride: usize) {
+        let idxs = strided_indices::<LANES>(slice.len(), start, stride);
+        // Safety: The indices have been bounds-checked.
+        unsafe { self.scatter_select_unchecked(slice, Mask::splat(true), idxs) }
+    }
+
     /// Write pointers elementwise into a SIMD vector.
     ///
     /// # Safety
@@ -593,6 +794,34 @@ where
     }
 }
 
+/// Returns the indices `start + i * stride` of a strided access to a slice of length `len`.
+///
+/// # Panics
+///
+/// Panics if any of the indices are out-of-bounds.
+#[inline]
+fn strided_indices<const LANES: usize>(
+    len: usize,
+    start: usize,
+    stride: usize,
+) -> Simd<usize, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let last = stride
+        .checked_mul(LANES - 1)
+        .and_then(|offset| offset.checked_add(start));
+    assert!(
+        matches!(last, Some(last) if last < len),
+        "strided access must be within the bounds of the slice"
+    );
+    let mut idxs = [start; LANES];
+    for (lane, idx) in idxs.iter_mut().enumerate() {
+        *idx += lane * stride;
+    }
+    Simd::from_array(idxs)
+}
+
 impl<T, const LANES: usize> Copy for Simd<T, LANES>
 where
     T: SimdElement,
@@ -912,4 +1141,65 @@ where
     T: core::ptr::Pointee<Metadata = ()>,
 {
     type Mask = isize;
-}
\ No newline at end of file
+}
+
+mod index {
+    use super::*;
+
+    /// Seals `SimdIndex`, and bounds-checks indices without widening them.
+    pub trait Sealed: SimdElement {
+        /// Returns which lanes of `idxs` are in-bounds for a slice of length `len`.
+        fn in_bounds<const LANES: usize>(idxs: Simd<Self, LANES>, len: usize) -> Mask<isize, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+    }
+
+    impl Sealed for usize {
+        #[inline]
+        fn in_bounds<const LANES: usize>(idxs: Simd<Self, LANES>, len: usize) -> Mask<isize, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            idxs.simd_lt(Simd::splat(len))
+        }
+    }
+
+    impl Sealed for u32 {
+        #[inline]
+        fn in_bounds<const LANES: usize>(idxs: Simd<Self, LANES>, len: usize) -> Mask<isize, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            match u32::try_from(len) {
+                Ok(len) => idxs.simd_lt(Simd::splat(len)).cast(),
+                Err(_) => Mask::splat(true),
+            }
+        }
+    }
+
+    impl Sealed for i32 {
+        #[inline]
+        fn in_bounds<const LANES: usize>(idxs: Simd<Self, LANES>, len: usize) -> Mask<isize, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            // Negative indices become at least 2^31 as `u32`, which is past every index
+            // that an `i32` can reach
+            let len = len.min(1 << 31) as u32;
+            idxs.cast::<u32>().simd_lt(Simd::splat(len)).cast()
+        }
+    }
+}
+
+/// Supporting trait for the index vectors of [`Simd::gather_or`], [`Simd::scatter`] and related
+/// functions.  Typically doesn't need to be used directly.
+///
+/// Indices narrower than `usize`, such as `u32`, are bounds-checked at their own width, and only
+/// widened where they are added to the base pointer, so that the bounds checks take half the
+/// registers of `usize` indices on 64-bit targets.
+/// Signed indices are permitted, but negative indices are always out-of-bounds.
+pub trait SimdIndex: SimdCast + index::Sealed {}
+
+impl SimdIndex for usize {}
+impl SimdIndex for u32 {}
+impl SimdIndex for i32 {}
@@ -17,6 +17,34 @@ macro_rules! from_transmute {
     };
 }
 
+/// Provides implementations of `From<$mask> for $vector` and `From<$vector> for $mask`.
+///
+/// Each lane of the vector is all ones if the mask lane is `true`, and all zeros otherwise.
+/// Converting a vector to a mask only tests the sign bit of each lane, as vendor instructions
+/// such as x86's `blendv` do.
+#[allow(unused)]
+macro_rules! from_mask {
+    { Mask<$int:ty, $lanes:literal> => $vector:ty } => {
+        impl core::convert::From<Mask<$int, $lanes>> for $vector {
+            #[inline]
+            fn from(value: Mask<$int, $lanes>) -> $vector {
+                // Safety: transmuting between vectors of the same size is safe
+                unsafe { core::mem::transmute(value.to_int()) }
+            }
+        }
+
+        impl core::convert::From<$vector> for Mask<$int, $lanes> {
+            #[inline]
+            fn from(value: $vector) -> Mask<$int, $lanes> {
+                use crate::simd::SimdPartialOrd;
+                // Safety: transmuting between vectors of the same size is safe
+                let value: Simd<$int, $lanes> = unsafe { core::mem::transmute(value) };
+                value.simd_lt(Simd::splat(0))
+            }
+        }
+    };
+}
+
 /// Conversions to x86's SIMD types.
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod x86;
@@ -29,3 +57,9 @@ mod arm;
 
 #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
 mod powerpc;
+
+#[cfg(target_arch = "loongarch64")]
+mod loongarch64;
+
+#[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+mod mips;
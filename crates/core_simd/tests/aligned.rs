@@ -0,0 +1,103 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{f64x2, u16x8, u8x16};
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn as_simd_covers_slice() {
+    let data: Vec<u8> = (0..=255).collect();
+    for start in 0..20 {
+        let slice = &data[start..];
+        let (prefix, middle, suffix) = u8x16::as_simd(slice);
+        assert!(prefix.len() < 16);
+        assert!(suffix.len() < 16);
+        assert!(u8x16::is_aligned(middle[0].as_array()));
+        let mut joined = prefix.to_vec();
+        joined.extend(middle.iter().flat_map(|v| v.to_array()));
+        joined.extend_from_slice(suffix);
+        assert_eq!(joined, slice);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn as_simd_mut_writes_through() {
+    let mut data = [1u16; 77];
+    let (prefix, middle, suffix) = u16x8::as_simd_mut(&mut data[3..]);
+    prefix.iter_mut().for_each(|x| *x = 2);
+    middle.iter_mut().for_each(|v| *v = u16x8::splat(2));
+    suffix.iter_mut().for_each(|x| *x = 2);
+    assert_eq!(data[..3], [1; 3]);
+    assert!(data[3..].iter().all(|&x| x == 2));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn aligned_load_store() {
+    let mut buffer = [f64x2::splat(0.); 2];
+    f64x2::from_array([1., 2.]).write_to_slice_aligned(buffer[1].as_mut_array());
+    assert_eq!(buffer[1].to_array(), [1., 2.]);
+    assert_eq!(f64x2::from_slice_aligned(buffer[1].as_array()), buffer[1]);
+}
+
+#[test]
+#[should_panic(expected = "slice must be aligned to the vector")]
+fn unaligned_load() {
+    let elements = [0u8; 64];
+    let offset = u8x16::as_simd(&elements).0.len() + 1;
+    let _ = u8x16::from_slice_aligned(&elements[offset..offset + 16]);
+}
+
+#[test]
+#[should_panic(expected = "slice length must be at least the number of lanes")]
+fn short_aligned_store() {
+    let mut buffer = u8x16::splat(0);
+    u8x16::splat(1).write_to_slice_aligned(&mut buffer.as_mut_array()[..8]);
+}
+
+#[cfg(feature = "alloc")]
+mod buffer {
+    use core_simd::simd::{u32x4, SimdBuffer};
+
+    #[test]
+    fn from_slice() {
+        let data: Vec<u32> = (0..10).collect();
+        let buffer = SimdBuffer::<u32, 4>::from_slice(&data);
+        assert_eq!(buffer.len(), 10);
+        assert_eq!(&buffer[..], &data[..]);
+        assert!(u32x4::is_aligned(&buffer));
+        let (vectors, remainder) = buffer.as_simd();
+        assert_eq!(
+            vectors,
+            [
+                u32x4::from_array([0, 1, 2, 3]),
+                u32x4::from_array([4, 5, 6, 7])
+            ]
+        );
+        assert_eq!(remainder, [8, 9]);
+    }
+
+    #[test]
+    fn splat() {
+        let mut buffer = SimdBuffer::<u32, 4>::splat(3, 8);
+        let (vectors, remainder) = buffer.as_simd_mut();
+        assert_eq!(vectors.len(), 2);
+        assert!(remainder.is_empty());
+        vectors[1] += u32x4::splat(1);
+        assert_eq!(&buffer[..], &[3, 3, 3, 3, 4, 4, 4, 4]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut buffer = SimdBuffer::<u32, 4>::new();
+        assert!(buffer.is_empty());
+        let (vectors, remainder) = buffer.as_simd_mut();
+        assert!(vectors.is_empty() && remainder.is_empty());
+    }
+}
@@ -0,0 +1,78 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{f32x8, prefetch_read, prefetch_write, u16x8, Locality};
+
+/// Orders the streaming stores made so far before any later memory access.
+fn store_fence() {
+    #[cfg(all(target_arch = "x86", target_feature = "sse"))]
+    // Safety: SSE is enabled.
+    unsafe {
+        core::arch::x86::_mm_sfence()
+    };
+    #[cfg(target_arch = "x86_64")]
+    // Safety: SSE is always available on x86-64.
+    unsafe {
+        core::arch::x86_64::_mm_sfence()
+    };
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn store_nontemporal_aligned() {
+    let mut dest = [f32x8::splat(0.); 4];
+    for (i, vector) in dest.iter_mut().enumerate() {
+        // Safety: the store fence is issued before `dest` is read.
+        unsafe { f32x8::splat(i as f32).store_nontemporal(vector.as_mut_array()) };
+    }
+    store_fence();
+    for (i, vector) in dest.iter().enumerate() {
+        assert_eq!(*vector, f32x8::splat(i as f32));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn store_nontemporal_unaligned() {
+    let mut dest = [0u16; 20];
+    for start in 0..=12 {
+        dest.fill(0);
+        // Safety: the store fence is issued before `dest` is read.
+        unsafe { u16x8::splat(7).store_nontemporal(&mut dest[start..]) };
+        store_fence();
+        assert!(dest[..start].iter().all(|&x| x == 0));
+        assert_eq!(dest[start..start + 8], [7; 8]);
+        assert!(dest[start + 8..].iter().all(|&x| x == 0));
+    }
+}
+
+#[test]
+#[should_panic]
+fn store_nontemporal_too_short() {
+    let mut dest = [0u16; 7];
+    // Safety: the store panics before writing anything.
+    unsafe { u16x8::splat(7).store_nontemporal(&mut dest) };
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn prefetch_any_pointer() {
+    let data = [1u8; 64];
+    for locality in [
+        Locality::None,
+        Locality::Low,
+        Locality::Moderate,
+        Locality::High,
+    ] {
+        prefetch_read(data.as_ptr(), locality);
+        prefetch_write(data.as_ptr(), locality);
+        prefetch_read(core::ptr::null::<u8>(), locality);
+        prefetch_write(data.as_ptr().wrapping_add(1 << 20), locality);
+    }
+    assert_eq!(data, [1; 64]);
+}
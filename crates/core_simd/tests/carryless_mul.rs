@@ -0,0 +1,48 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_carryless_mul {
+    { $($ty:ident),* } => {
+        $(
+            mod $ty {
+                #[cfg(target_arch = "wasm32")]
+                use wasm_bindgen_test::*;
+
+                use core_simd::simd::{Simd, SimdUint};
+
+                /// Multiplies without carries one bit at a time.
+                fn carryless_mul(a: $ty, b: $ty) -> ($ty, $ty) {
+                    let mut product = 0u128;
+                    for i in 0..<$ty>::BITS {
+                        if b >> i & 1 == 1 {
+                            product ^= (a as u128) << i;
+                        }
+                    }
+                    (product as $ty, (product >> <$ty>::BITS) as $ty)
+                }
+
+                test_helpers::test_lanes! {
+                    fn carryless_mul_matches_scalar<const LANES: usize>() {
+                        test_helpers::test_2(&|a: [$ty; LANES], b: [$ty; LANES]| {
+                            let (low, high) = Simd::from_array(a).carryless_mul(Simd::from_array(b));
+                            for lane in 0..LANES {
+                                proptest::prop_assert_eq!(
+                                    (low[lane], high[lane]),
+                                    carryless_mul(a[lane], b[lane])
+                                );
+                            }
+                            Ok(())
+                        });
+                    }
+                }
+            }
+        )*
+    }
+}
+
+test_carryless_mul! { u8, u16, u32, u64, usize }
@@ -0,0 +1,103 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{
+    f32x4, i32x4, mask32x4, u8x8, Mask, SimdFloat, SimdPartialEq, SimdPartialOrd,
+};
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn splat() {
+    const V: u8x8 = u8x8::splat(7);
+    assert_eq!(V, u8x8::splat(7));
+    assert_eq!(V.to_array(), [7; 8]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mask() {
+    const ALL: mask32x4 = Mask::splat(true);
+    const NONE: mask32x4 = Mask::splat(false);
+    const SOME: mask32x4 = Mask::from_array([true, false, false, true]);
+    assert!(ALL.all());
+    assert!(!NONE.any());
+    assert_eq!(SOME.to_array(), [true, false, false, true]);
+    assert_eq!(SOME, mask32x4::from_array([true, false, false, true]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn integer_arithmetic() {
+    const A: i32x4 = i32x4::from_array([i32::MAX, -2, 3, 40]);
+    const B: i32x4 = i32x4::from_array([1, 5, -6, 3]);
+    const SUM: i32x4 = A.const_add(B);
+    const DIFFERENCE: i32x4 = A.const_sub(B);
+    const PRODUCT: i32x4 = A.const_mul(B);
+    const SHL: i32x4 = A.const_shl(B);
+    const SHR: i32x4 = A.const_shr(B);
+    const NEG: i32x4 = A.const_neg();
+    assert_eq!(SUM, A + B);
+    assert_eq!(DIFFERENCE, A - B);
+    assert_eq!(PRODUCT, A * B);
+    assert_eq!(SHL, A << B);
+    assert_eq!(SHR, A >> B);
+    assert_eq!(NEG, -A);
+    assert_eq!(A.const_and(B), A & B);
+    assert_eq!(A.const_or(B), A | B);
+    assert_eq!(A.const_xor(B), A ^ B);
+    assert_eq!(A.const_not(), !A);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float_arithmetic() {
+    const A: f32x4 = f32x4::from_array([1.5, -2., f32::NAN, 0.25]);
+    const B: f32x4 = f32x4::from_array([0.5, 4., 1., f32::NAN]);
+    const MIN: f32x4 = A.const_min(B);
+    const MAX: f32x4 = A.const_max(B);
+    assert_eq!(A.const_add(B).to_bits(), (A + B).to_bits());
+    assert_eq!(A.const_sub(B).to_bits(), (A - B).to_bits());
+    assert_eq!(A.const_mul(B).to_bits(), (A * B).to_bits());
+    assert_eq!(A.const_div(B).to_bits(), (A / B).to_bits());
+    assert_eq!(A.const_neg().to_bits(), (-A).to_bits());
+    assert_eq!(MIN.to_array(), [0.5, -2., 1., 0.25]);
+    assert_eq!(MAX.to_array(), [1.5, 4., 1., 0.25]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn comparisons() {
+    const A: i32x4 = i32x4::from_array([1, 2, 3, 4]);
+    const B: i32x4 = i32x4::from_array([4, 2, 3, 1]);
+    const LT: mask32x4 = A.const_lt(B);
+    assert_eq!(LT, A.simd_lt(B));
+    assert_eq!(A.const_le(B), A.simd_le(B));
+    assert_eq!(A.const_gt(B), A.simd_gt(B));
+    assert_eq!(A.const_ge(B), A.simd_ge(B));
+    assert_eq!(A.const_eq(B), A.simd_eq(B));
+    assert_eq!(A.const_ne(B), A.simd_ne(B));
+    assert_eq!(A.const_min(B).to_array(), [1, 2, 3, 1]);
+    assert_eq!(A.const_max(B).to_array(), [4, 2, 3, 4]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn swizzles() {
+    const A: i32x4 = i32x4::from_array([0, 1, 2, 3]);
+    const B: i32x4 = i32x4::from_array([4, 5, 6, 7]);
+    const REVERSE: i32x4 = A.reverse();
+    const LEFT: i32x4 = A.rotate_lanes_left::<1>();
+    const RIGHT: i32x4 = A.rotate_lanes_right::<1>();
+    const INTERLEAVE: (i32x4, i32x4) = A.interleave(B);
+    const DEINTERLEAVE: (i32x4, i32x4) = INTERLEAVE.0.deinterleave(INTERLEAVE.1);
+    assert_eq!(REVERSE, A.reverse());
+    assert_eq!(LEFT, A.rotate_lanes_left::<1>());
+    assert_eq!(RIGHT, A.rotate_lanes_right::<1>());
+    assert_eq!(INTERLEAVE, A.interleave(B));
+    assert_eq!(DEINTERLEAVE, (A, B));
+}
@@ -0,0 +1,85 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{i32x4, u32x4, Simd};
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gather_u32_indices() {
+    let vec = [10, 11, 12, 13, 14, 15];
+    let idxs = u32x4::from_array([5, 0, 6, 2]);
+    let result = Simd::gather_or_default(&vec, idxs);
+    assert_eq!(result.to_array(), [15, 10, 0, 12]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gather_i32_indices() {
+    let vec = [10, 11, 12, 13, 14, 15];
+    let idxs = i32x4::from_array([1, -1, i32::MIN, 4]);
+    let result = Simd::gather_or(&vec, idxs, Simd::splat(-1));
+    assert_eq!(result.to_array(), [11, -1, -1, 14]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scatter_narrow_indices() {
+    let mut vec = [0; 6];
+    let values = Simd::from_array([1, 2, 3, 4]);
+    values.scatter(&mut vec, u32x4::from_array([5, 0, 9, 2]));
+    assert_eq!(vec, [2, 0, 4, 0, 0, 1]);
+    values.scatter(&mut vec, i32x4::from_array([-1, 1, 3, -6]));
+    assert_eq!(vec, [2, 2, 4, 3, 0, 1]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gather_strided() {
+    let vec: Vec<u32> = (0..16).collect();
+    assert_eq!(
+        Simd::<u32, 4>::gather_strided(&vec, 0, 5).to_array(),
+        [0, 5, 10, 15]
+    );
+    assert_eq!(
+        Simd::<u32, 4>::gather_strided(&vec, 3, 0).to_array(),
+        [3; 4]
+    );
+    assert_eq!(
+        Simd::<u32, 8>::gather_strided(&vec, 0, 2).to_array(),
+        [0, 2, 4, 6, 8, 10, 12, 14]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scatter_strided() {
+    let mut vec = [0u32; 8];
+    Simd::from_array([1, 2, 3, 4]).scatter_strided(&mut vec, 1, 2);
+    assert_eq!(vec, [0, 1, 0, 2, 0, 3, 0, 4]);
+}
+
+#[test]
+#[should_panic]
+fn gather_strided_out_of_bounds() {
+    let vec = [0u32; 15];
+    let _ = Simd::<u32, 4>::gather_strided(&vec, 0, 5);
+}
+
+#[test]
+#[should_panic]
+fn gather_strided_overflow() {
+    let vec = [0u32; 16];
+    let _ = Simd::<u32, 4>::gather_strided(&vec, 1, usize::MAX / 2);
+}
+
+#[test]
+#[should_panic]
+fn scatter_strided_out_of_bounds() {
+    let mut vec = [0u32; 8];
+    Simd::from_array([1, 2, 3, 4]).scatter_strided(&mut vec, 2, 2);
+}
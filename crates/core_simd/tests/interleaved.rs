@@ -0,0 +1,92 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{i16x8, u32x4, u8x16, Simd};
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn load_interleaved() {
+    let records: Vec<u32> = (0..17).collect();
+    let [even, odd] = u32x4::load_interleaved::<2>(&records);
+    assert_eq!(even.to_array(), [0, 2, 4, 6]);
+    assert_eq!(odd.to_array(), [1, 3, 5, 7]);
+    let [a, b, c, d] = u32x4::load_interleaved::<4>(&records);
+    assert_eq!(a.to_array(), [0, 4, 8, 12]);
+    assert_eq!(b.to_array(), [1, 5, 9, 13]);
+    assert_eq!(c.to_array(), [2, 6, 10, 14]);
+    assert_eq!(d.to_array(), [3, 7, 11, 15]);
+    let [all] = u32x4::load_interleaved::<1>(&records);
+    assert_eq!(all, u32x4::from_slice(&records));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rgb_round_trip() {
+    let pixels: Vec<u8> = (0..48).collect();
+    let [r, g, b] = u8x16::load_interleaved::<3>(&pixels);
+    assert_eq!(g.to_array()[..4], [1, 4, 7, 10]);
+    let mut output = vec![0u8; 48];
+    u8x16::store_interleaved([r, g, b], &mut output);
+    assert_eq!(output, pixels);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn round_trip_all_record_sizes() {
+    fn check<const LANES: usize, const K: usize>()
+    where
+        core_simd::simd::LaneCount<LANES>: core_simd::simd::SupportedLaneCount,
+    {
+        let records: Vec<u16> = (0..(K * LANES) as u16).collect();
+        let fields = Simd::<u16, LANES>::load_interleaved::<K>(&records);
+        for (k, field) in fields.iter().enumerate() {
+            for (i, &x) in field.as_array().iter().enumerate() {
+                assert_eq!(x as usize, i * K + k);
+            }
+        }
+        let mut output = vec![0; K * LANES];
+        Simd::store_interleaved(fields, &mut output);
+        assert_eq!(output, records);
+    }
+    check::<1, 3>();
+    check::<2, 3>();
+    check::<4, 3>();
+    check::<8, 3>();
+    check::<64, 3>();
+    check::<1, 4>();
+    check::<2, 4>();
+    check::<8, 4>();
+    check::<64, 4>();
+    check::<8, 2>();
+    check::<4, 5>();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn store_interleaved_leaves_tail() {
+    let mut output = [-1i16; 20];
+    let x = i16x8::splat(1);
+    let y = i16x8::splat(2);
+    i16x8::store_interleaved([x, y], &mut output);
+    assert_eq!(output[..16], [1, 2].repeat(8));
+    assert_eq!(output[16..], [-1; 4]);
+}
+
+#[test]
+#[should_panic]
+fn load_interleaved_too_short() {
+    let records = [0u32; 11];
+    let _ = u32x4::load_interleaved::<3>(&records);
+}
+
+#[test]
+#[should_panic]
+fn store_interleaved_too_short() {
+    let mut records = [0u32; 7];
+    u32x4::store_interleaved([u32x4::splat(0); 2], &mut records);
+}
@@ -0,0 +1,73 @@
+#![feature(portable_simd, stdsimd)]
+#![cfg(target_arch = "loongarch64")]
+
+use core::arch::loongarch64::*;
+
+use core_simd::simd::{
+    f32x4, f32x8, f64x2, f64x4, i16x16, i16x8, i32x4, i32x8, i64x2, i64x4, i8x16, i8x32, isizex2,
+    isizex4, u16x16, u16x8, u32x4, u32x8, u64x2, u64x4, u8x16, u8x32, usizex2, usizex4,
+};
+
+#[test]
+fn lsx_vectors() {
+    let v = u8x16::from_array(core::array::from_fn(|i| i as u8));
+    assert_eq!(u8x16::from(v16u8::from(v)), v);
+    let v = i8x16::from_array(core::array::from_fn(|i| -(i as i8)));
+    assert_eq!(i8x16::from(v16i8::from(v)), v);
+
+    let v = u16x8::from_array([1, 2, 3, 4, 5, 6, 7, u16::MAX]);
+    assert_eq!(u16x8::from(v8u16::from(v)), v);
+    let v = i16x8::from_array([-1, 2, -3, 4, -5, 6, -7, i16::MIN]);
+    assert_eq!(i16x8::from(v8i16::from(v)), v);
+
+    let v = u32x4::from_array([1, 2, 3, u32::MAX]);
+    assert_eq!(u32x4::from(v4u32::from(v)), v);
+    let v = i32x4::from_array([-1, 2, -3, i32::MIN]);
+    assert_eq!(i32x4::from(v4i32::from(v)), v);
+    let v = f32x4::from_array([1., -2., 3.5, f32::MAX]);
+    assert_eq!(f32x4::from(v4f32::from(v)), v);
+
+    let v = u64x2::from_array([1, u64::MAX]);
+    assert_eq!(u64x2::from(v2u64::from(v)), v);
+    let v = i64x2::from_array([-1, i64::MIN]);
+    assert_eq!(i64x2::from(v2i64::from(v)), v);
+    let v = f64x2::from_array([1., f64::MIN_POSITIVE]);
+    assert_eq!(f64x2::from(v2f64::from(v)), v);
+
+    let v = usizex2::from_array([1, usize::MAX]);
+    assert_eq!(usizex2::from(v2u64::from(v)), v);
+    let v = isizex2::from_array([-1, isize::MIN]);
+    assert_eq!(isizex2::from(v2i64::from(v)), v);
+}
+
+#[test]
+fn lasx_vectors() {
+    let v = u8x32::from_array(core::array::from_fn(|i| i as u8));
+    assert_eq!(u8x32::from(v32u8::from(v)), v);
+    let v = i8x32::from_array(core::array::from_fn(|i| -(i as i8)));
+    assert_eq!(i8x32::from(v32i8::from(v)), v);
+
+    let v = u16x16::from_array(core::array::from_fn(|i| i as u16 * 4097));
+    assert_eq!(u16x16::from(v16u16::from(v)), v);
+    let v = i16x16::from_array(core::array::from_fn(|i| -(i as i16) * 2049));
+    assert_eq!(i16x16::from(v16i16::from(v)), v);
+
+    let v = u32x8::from_array([1, 2, 3, 4, 5, 6, 7, u32::MAX]);
+    assert_eq!(u32x8::from(v8u32::from(v)), v);
+    let v = i32x8::from_array([-1, 2, -3, 4, -5, 6, -7, i32::MIN]);
+    assert_eq!(i32x8::from(v8i32::from(v)), v);
+    let v = f32x8::from_array([1., -2., 3.5, -4., 5., -6., 7., f32::MAX]);
+    assert_eq!(f32x8::from(v8f32::from(v)), v);
+
+    let v = u64x4::from_array([1, 2, 3, u64::MAX]);
+    assert_eq!(u64x4::from(v4u64::from(v)), v);
+    let v = i64x4::from_array([-1, 2, -3, i64::MIN]);
+    assert_eq!(i64x4::from(v4i64::from(v)), v);
+    let v = f64x4::from_array([1., -2., 3.5, f64::MIN_POSITIVE]);
+    assert_eq!(f64x4::from(v4f64::from(v)), v);
+
+    let v = usizex4::from_array([1, 2, 3, usize::MAX]);
+    assert_eq!(usizex4::from(v4u64::from(v)), v);
+    let v = isizex4::from_array([-1, 2, -3, isize::MIN]);
+    assert_eq!(isizex4::from(v4i64::from(v)), v);
+}
@@ -103,6 +103,85 @@ macro_rules! test_mask_api {
                 assert_eq!(Mask::<$type, 2>::from_bitmask(bitmask), mask);
             }
 
+            #[test]
+            fn roundtrip_bitmask_conversion_wide() {
+                use core_simd::simd::ToBitMask;
+
+                let values = core::array::from_fn(|i| i % 3 == 0 || i == 127);
+                let mask = Mask::<$type, 128>::from_array(values);
+                let bitmask: u128 = mask.to_bitmask();
+                for (i, value) in values.iter().enumerate() {
+                    assert_eq!(bitmask >> i & 1 == 1, *value);
+                }
+                assert_eq!(Mask::<$type, 128>::from_bitmask(bitmask), mask);
+
+                let values = core::array::from_fn(|i| i % 5 == 0 || i == 255);
+                let mask = Mask::<$type, 256>::from_array(values);
+                let bitmask: [u8; 32] = mask.to_bitmask();
+                for (i, value) in values.iter().enumerate() {
+                    assert_eq!(bitmask[i / 8] >> (i % 8) & 1 == 1, *value);
+                }
+                assert_eq!(Mask::<$type, 256>::from_bitmask(bitmask), mask);
+            }
+
+            #[test]
+            fn roundtrip_bitmask_bit_order() {
+                use core_simd::simd::ToBitMask;
+
+                let values = [
+                    true, false, false, true, false, false, true, false,
+                    true, true, false, false, false, false, false, true,
+                ];
+                let mask = Mask::<$type, 16>::from_array(values);
+                assert_eq!(mask.to_bitmask_lsb0(), 0b1000001101001001);
+                assert_eq!(mask.to_bitmask_msb0(), 0b1001001011000001);
+                assert_eq!(mask.to_bitmask_le().to_ne_bytes(), [0b01001001, 0b10000011]);
+                assert_eq!(mask.to_bitmask_be().to_ne_bytes(), [0b10010010, 0b11000001]);
+                assert_eq!(Mask::<$type, 16>::from_bitmask_lsb0(mask.to_bitmask_lsb0()), mask);
+                assert_eq!(Mask::<$type, 16>::from_bitmask_msb0(mask.to_bitmask_msb0()), mask);
+                assert_eq!(Mask::<$type, 16>::from_bitmask_le(mask.to_bitmask_le()), mask);
+                assert_eq!(Mask::<$type, 16>::from_bitmask_be(mask.to_bitmask_be()), mask);
+
+                let mask = Mask::<$type, 4>::from_array([true, true, false, false]);
+                assert_eq!(mask.to_bitmask_msb0(), 0b11000000);
+                assert_eq!(Mask::<$type, 4>::from_bitmask_msb0(0b11001111), mask);
+
+                let values = core::array::from_fn(|i| i == 0 || i == 9);
+                let mask = Mask::<$type, 256>::from_array(values);
+                let mut le = [0; 32];
+                le[0] = 0b00000001;
+                le[1] = 0b00000010;
+                let mut be = [0; 32];
+                be[0] = 0b10000000;
+                be[1] = 0b01000000;
+                assert_eq!(mask.to_bitmask_le(), le);
+                assert_eq!(mask.to_bitmask_be(), be);
+                assert_eq!(Mask::<$type, 256>::from_bitmask_le(le), mask);
+                assert_eq!(Mask::<$type, 256>::from_bitmask_be(be), mask);
+            }
+
+            #[cfg(feature = "all_lane_counts")]
+            #[test]
+            fn roundtrip_bitmask_conversion_odd() {
+                use core_simd::simd::ToBitMask;
+
+                let values = [true, false, true, true, false, false, true];
+                let mask = Mask::<$type, 7>::from_array(values);
+                let bitmask = mask.to_bitmask();
+                assert_eq!(bitmask, 0b1001101);
+                assert_eq!(Mask::<$type, 7>::from_bitmask(bitmask), mask);
+
+                let values = core::array::from_fn(|i| i % 3 == 0);
+                let mask = Mask::<$type, 24>::from_array(values);
+                let bitmask: u32 = mask.to_bitmask();
+                assert_eq!(bitmask, 0b001001001001001001001001);
+                assert_eq!(Mask::<$type, 24>::from_bitmask(bitmask), mask);
+
+                // Bits beyond the last lane are ignored
+                assert_eq!(Mask::<$type, 24>::from_bitmask(bitmask | 0xff000000), mask);
+                assert_eq!(Mask::<$type, 24>::from_bitmask(u32::MAX), Mask::splat(true));
+            }
+
             #[test]
             fn cast() {
                 fn cast_impl<T: core_simd::simd::MaskElement>()
@@ -0,0 +1,40 @@
+#![feature(portable_simd, stdsimd)]
+#![cfg(any(target_arch = "mips", target_arch = "mips64"))]
+
+#[cfg(target_arch = "mips")]
+use core::arch::mips::*;
+#[cfg(target_arch = "mips64")]
+use core::arch::mips64::*;
+
+use core_simd::simd::{f32x4, f64x2, i16x8, i32x4, i64x2, i8x16, u16x8, u32x4, u64x2, u8x16};
+
+#[test]
+fn integer_vectors() {
+    let v = u8x16::from_array(core::array::from_fn(|i| i as u8));
+    assert_eq!(u8x16::from(v16u8::from(v)), v);
+    let v = i8x16::from_array(core::array::from_fn(|i| -(i as i8)));
+    assert_eq!(i8x16::from(v16i8::from(v)), v);
+
+    let v = u16x8::from_array([1, 2, 3, 4, 5, 6, 7, u16::MAX]);
+    assert_eq!(u16x8::from(v8u16::from(v)), v);
+    let v = i16x8::from_array([-1, 2, -3, 4, -5, 6, -7, i16::MIN]);
+    assert_eq!(i16x8::from(v8i16::from(v)), v);
+
+    let v = u32x4::from_array([1, 2, 3, u32::MAX]);
+    assert_eq!(u32x4::from(v4u32::from(v)), v);
+    let v = i32x4::from_array([-1, 2, -3, i32::MIN]);
+    assert_eq!(i32x4::from(v4i32::from(v)), v);
+
+    let v = u64x2::from_array([1, u64::MAX]);
+    assert_eq!(u64x2::from(v2u64::from(v)), v);
+    let v = i64x2::from_array([-1, i64::MIN]);
+    assert_eq!(i64x2::from(v2i64::from(v)), v);
+}
+
+#[test]
+fn float_vectors() {
+    let v = f32x4::from_array([1., -2., 3.5, f32::MAX]);
+    assert_eq!(f32x4::from(v4f32::from(v)), v);
+    let v = f64x2::from_array([1., f64::MIN_POSITIVE]);
+    assert_eq!(f64x2::from(v2f64::from(v)), v);
+}
@@ -0,0 +1,58 @@
+#![feature(portable_simd, stdsimd)]
+#![cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+
+#[cfg(target_arch = "powerpc")]
+use core::arch::powerpc::*;
+#[cfg(target_arch = "powerpc64")]
+use core::arch::powerpc64::*;
+
+use core_simd::simd::{
+    f32x4, f64x2, i16x8, i32x4, i64x2, i8x16, mask16x8, mask32x4, mask64x2, mask8x16, u16x8, u32x4,
+    u64x2, u8x16,
+};
+
+#[test]
+fn integer_vectors() {
+    let v = u8x16::from_array(core::array::from_fn(|i| i as u8));
+    assert_eq!(u8x16::from(vector_unsigned_char::from(v)), v);
+    let v = i8x16::from_array(core::array::from_fn(|i| -(i as i8)));
+    assert_eq!(i8x16::from(vector_signed_char::from(v)), v);
+
+    let v = u16x8::from_array([1, 2, 3, 4, 5, 6, 7, u16::MAX]);
+    assert_eq!(u16x8::from(vector_unsigned_short::from(v)), v);
+    let v = i16x8::from_array([-1, 2, -3, 4, -5, 6, -7, i16::MIN]);
+    assert_eq!(i16x8::from(vector_signed_short::from(v)), v);
+
+    let v = u32x4::from_array([1, 2, 3, u32::MAX]);
+    assert_eq!(u32x4::from(vector_unsigned_int::from(v)), v);
+    let v = i32x4::from_array([-1, 2, -3, i32::MIN]);
+    assert_eq!(i32x4::from(vector_signed_int::from(v)), v);
+
+    let v = u64x2::from_array([1, u64::MAX]);
+    assert_eq!(u64x2::from(vector_unsigned_long::from(v)), v);
+    let v = i64x2::from_array([-1, i64::MIN]);
+    assert_eq!(i64x2::from(vector_signed_long::from(v)), v);
+}
+
+#[test]
+fn float_vectors() {
+    let v = f32x4::from_array([1., -2., 3.5, f32::MAX]);
+    assert_eq!(f32x4::from(vector_float::from(v)), v);
+    let v = f64x2::from_array([1., f64::MIN_POSITIVE]);
+    assert_eq!(f64x2::from(vector_double::from(v)), v);
+}
+
+#[test]
+fn bool_vectors() {
+    let mask = mask8x16::from_array(core::array::from_fn(|i| i % 3 == 0));
+    assert_eq!(mask8x16::from(vector_bool_char::from(mask)), mask);
+
+    let mask = mask16x8::from_array([true, false, true, true, false, false, true, false]);
+    assert_eq!(mask16x8::from(vector_bool_short::from(mask)), mask);
+
+    let mask = mask32x4::from_array([true, false, false, true]);
+    assert_eq!(mask32x4::from(vector_bool_int::from(mask)), mask);
+
+    let mask = mask64x2::from_array([false, true]);
+    assert_eq!(mask64x2::from(vector_bool_long::from(mask)), mask);
+}
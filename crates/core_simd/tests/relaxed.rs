@@ -0,0 +1,78 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{f32x4, f64x2, f64x8, u8x16, u8x32};
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn relaxed_mul_add() {
+    // Exactly representable, so fused and unfused results agree.
+    let x = f32x4::from_array([1.5, -2., 0.25, 8.]);
+    let y = x.relaxed_mul_add(f32x4::splat(4.), f32x4::splat(1.));
+    assert_eq!(y.to_array(), [7., -7., 2., 33.]);
+
+    let x = f64x2::from_array([3., -0.5]);
+    assert_eq!(
+        x.relaxed_mul_add(x, f64x2::splat(-1.)).to_array(),
+        [8., -0.75]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn relaxed_min_max() {
+    let a = f64x8::from_array([1., 2., -3., 4., 5., -6., 7., 8.]);
+    let b = f64x8::from_array([8., -7., 6., 5., 4., 3., 2., 1.]);
+    assert_eq!(
+        a.relaxed_min(b).to_array(),
+        [1., -7., -3., 4., 4., -6., 2., 1.]
+    );
+    assert_eq!(
+        a.relaxed_max(b).to_array(),
+        [8., 2., 6., 5., 5., 3., 7., 8.]
+    );
+
+    // The result for NaN is unspecified, but must be either lane or NaN.
+    let nan = f32x4::splat(f32::NAN);
+    let one = f32x4::splat(1.);
+    for lane in nan.relaxed_min(one).to_array() {
+        assert!(lane.is_nan() || lane == 1.);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn relaxed_swizzle_dyn() {
+    let table = u8x16::from_array([
+        10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    ]);
+    let idxs = u8x16::from_array([15, 0, 1, 1, 2, 3, 5, 8, 13, 4, 4, 4, 7, 6, 9, 10]);
+    assert_eq!(
+        table.relaxed_swizzle_dyn(idxs).to_array(),
+        [25, 10, 11, 11, 12, 13, 15, 18, 23, 14, 14, 14, 17, 16, 19, 20]
+    );
+
+    let table = u8x32::from_array(core::array::from_fn(|i| i as u8 * 2));
+    let idxs = u8x32::from_array(core::array::from_fn(|i| 31 - i as u8));
+    assert_eq!(
+        table.relaxed_swizzle_dyn(idxs).to_array(),
+        core::array::from_fn(|i| (31 - i as u8) * 2)
+    );
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen_test]
+fn mask_v128() {
+    use core::arch::wasm32::v128;
+    use core_simd::simd::{i32x4, mask32x4};
+
+    let mask = mask32x4::from_array([true, false, false, true]);
+    let vector = v128::from(mask);
+    assert_eq!(i32x4::from(vector).to_array(), [-1, 0, 0, -1]);
+    assert_eq!(mask32x4::from(vector), mask);
+}
@@ -0,0 +1,64 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{f32x4, i32x4, u32x8, u8x16, Simd};
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn conflict_mask() {
+    let idxs = u32x8::from_array([1, 1, 1, 2, 3, 2, 9, 0]);
+    assert_eq!(
+        idxs.conflict_mask().to_array(),
+        [false, true, true, false, false, true, false, false]
+    );
+    let unique = i32x4::from_array([-1, 0, 1, 2]);
+    assert!(!unique.conflict_mask().any());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scatter_add_histogram() {
+    let data = u8x16::from_array([0, 1, 2, 3, 3, 3, 3, 1, 0, 9, 200, 2, 2, 2, 2, 2]);
+    let mut histogram = [0u32; 4];
+    Simd::<u32, 16>::splat(1).scatter_add(&mut histogram, data.cast::<u32>());
+    assert_eq!(histogram, [2, 2, 6, 4]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scatter_add_matches_scalar() {
+    let idxs = Simd::from_array([4usize, 4, 4, 4, 0, 7, 4, 100]);
+    let values = Simd::from_array([1i32, -2, 3, -4, 5, 6, 7, 8]);
+    let mut expected = [10i32; 8];
+    for (&idx, &value) in idxs.as_array().iter().zip(values.as_array()) {
+        if let Some(element) = expected.get_mut(idx) {
+            *element += value;
+        }
+    }
+    let mut actual = [10i32; 8];
+    values.scatter_add(&mut actual, idxs);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scatter_max() {
+    let mut maxima = [0.0, 1.0, f32::NAN];
+    let idxs = i32x4::from_array([0, 0, 2, -1]);
+    f32x4::from_array([-1.0, 3.0, 2.0, 100.0]).scatter_max(&mut maxima, idxs);
+    assert_eq!(maxima, [3.0, 1.0, 2.0]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn scatter_or() {
+    let mut flags = [0u32; 3];
+    let idxs = u32x8::from_array([2, 2, 2, 2, 0, 0, 1, 3]);
+    Simd::from_array([1, 2, 4, 8, 16, 32, 64, 128]).scatter_or(&mut flags, idxs);
+    assert_eq!(flags, [48, 64, 15]);
+}
@@ -0,0 +1,161 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Returns the first `len` elements of `elements` repeated, so that slices span several blocks
+/// of every lane count.
+fn repeat<T: Copy>(elements: &[T], len: u16) -> Vec<T> {
+    elements
+        .iter()
+        .copied()
+        .cycle()
+        .take(usize::from(len) % 600)
+        .collect()
+}
+
+macro_rules! test_slice {
+    { $($ty:ident: $add:path, $mul:path, $min:path, $max:path, $tame:expr;)* } => {
+        $(
+            mod $ty {
+                #[cfg(target_arch = "wasm32")]
+                use wasm_bindgen_test::*;
+
+                use super::repeat;
+                use core_simd::simd::slice;
+
+                /// Keeps most values small enough that sums aren't dominated by overflow.
+                fn tame(values: [$ty; 64]) -> [$ty; 64] {
+                    values.map($tame)
+                }
+
+                /// Adds in the order documented for `slice::sum`.
+                fn sum_reference<const LANES: usize>(terms: impl Iterator<Item = $ty>) -> $ty {
+                    let zero = -0.0 as $ty;
+                    let mut accs = [[zero; LANES]; 4];
+                    for (i, term) in terms.enumerate() {
+                        let acc = &mut accs[i / LANES % 4][i % LANES];
+                        *acc = $add(*acc, term);
+                    }
+                    (0..LANES)
+                        .map(|lane| {
+                            $add(
+                                $add(accs[0][lane], accs[1][lane]),
+                                $add(accs[2][lane], accs[3][lane]),
+                            )
+                        })
+                        .fold(zero, $add)
+                }
+
+                /// Returns the index of the first element that no other element is better than.
+                fn position_reference(xs: &[$ty], better: impl Fn($ty, $ty) -> bool) -> Option<usize> {
+                    let mut position: Option<usize> = None;
+                    for (i, &x) in xs.iter().enumerate() {
+                        #[allow(clippy::eq_op)]
+                        let ordered = x == x;
+                        if ordered && position.map_or(true, |best| better(x, xs[best])) {
+                            position = Some(i);
+                        }
+                    }
+                    position
+                }
+
+                test_helpers::test_lanes! {
+                    fn reductions_match_reference<const LANES: usize>() {
+                        test_helpers::test_2(&|values: [$ty; 64], len: u16| {
+                            let xs = repeat(&tame(values), len);
+                            let ys = repeat(&tame(values).map(|y| $mul(y, 3 as $ty)), len.wrapping_add(1));
+                            let ys = &ys[..ys.len().min(xs.len())];
+                            let xs = &xs[..ys.len()];
+
+                            test_helpers::prop_assert_biteq!(
+                                slice::sum::<_, LANES>(xs),
+                                sum_reference::<LANES>(xs.iter().copied())
+                            );
+                            test_helpers::prop_assert_biteq!(
+                                slice::dot::<_, LANES>(xs, ys),
+                                sum_reference::<LANES>(xs.iter().zip(ys).map(|(&x, &y)| $mul(x, y)))
+                            );
+
+                            let min = xs.iter().copied().reduce($min);
+                            let max = xs.iter().copied().reduce($max);
+                            proptest::prop_assert_eq!(slice::min::<_, LANES>(xs).is_some(), min.is_some());
+                            if let (Some(actual), Some(expected)) = (slice::min::<_, LANES>(xs), min) {
+                                test_helpers::prop_assert_biteq!(actual, expected);
+                            }
+                            if let (Some(actual), Some(expected)) = (slice::max::<_, LANES>(xs), max) {
+                                test_helpers::prop_assert_biteq!(actual, expected);
+                            }
+
+                            proptest::prop_assert_eq!(
+                                slice::argmin::<_, LANES>(xs),
+                                position_reference(xs, |x, best| x < best)
+                            );
+                            proptest::prop_assert_eq!(
+                                slice::argmax::<_, LANES>(xs),
+                                position_reference(xs, |x, best| x > best)
+                            );
+
+                            if let Some(&value) = xs.get(usize::from(len) % 7) {
+                                proptest::prop_assert_eq!(
+                                    slice::count_eq::<_, LANES>(xs, value),
+                                    xs.iter().filter(|&&x| x == value).count()
+                                );
+                            }
+                            Ok(())
+                        });
+                    }
+
+                    fn transforms_match_scalar<const LANES: usize>() {
+                        test_helpers::test_3(&|values: [$ty; 64], a: $ty, len: u16| {
+                            let xs = repeat(&tame(values), len);
+                            let ys = repeat(&tame(values).map(|y| $add(y, 1 as $ty)), len);
+
+                            let mut actual = ys.clone();
+                            slice::axpy::<_, LANES>(a, &xs, &mut actual);
+                            let expected: Vec<_> = xs.iter().zip(&ys).map(|(&x, &y)| $add($mul(a, x), y)).collect();
+                            for (actual, expected) in actual.iter().zip(&expected) {
+                                test_helpers::prop_assert_biteq!(*actual, *expected);
+                            }
+
+                            let mut actual = xs.clone();
+                            slice::scale::<_, LANES>(a, &mut actual);
+                            for (actual, &x) in actual.iter().zip(&xs) {
+                                test_helpers::prop_assert_biteq!(*actual, $mul(a, x));
+                            }
+                            Ok(())
+                        });
+                    }
+                }
+            }
+        )*
+    }
+}
+
+test_slice! {
+    f32: core::ops::Add::add, core::ops::Mul::mul, f32::min, f32::max, |x| if x.is_finite() { x % 1000. } else { x };
+    f64: core::ops::Add::add, core::ops::Mul::mul, f64::min, f64::max, |x| if x.is_finite() { x % 1000. } else { x };
+    i8: i8::wrapping_add, i8::wrapping_mul, core::cmp::min, core::cmp::max, |x| x;
+    i32: i32::wrapping_add, i32::wrapping_mul, core::cmp::min, core::cmp::max, |x| x;
+    u16: u16::wrapping_add, u16::wrapping_mul, core::cmp::min, core::cmp::max, |x| x;
+    u64: u64::wrapping_add, u64::wrapping_mul, core::cmp::min, core::cmp::max, |x| x;
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float_edge_cases() {
+    use core_simd::simd::slice;
+    assert_eq!(slice::sum::<f32, 4>(&[]).to_bits(), (-0.0f32).to_bits());
+    assert_eq!(
+        slice::sum::<f32, 4>(&[-0.0, -0.0]).to_bits(),
+        (-0.0f32).to_bits()
+    );
+    assert!(slice::min::<f32, 4>(&[f32::NAN; 9]).unwrap().is_nan());
+    assert_eq!(slice::argmax::<f32, 4>(&[f32::NAN; 9]), None);
+    assert_eq!(slice::argmin::<f32, 4>(&[f32::INFINITY; 9]), Some(0));
+    assert_eq!(slice::argmax::<f32, 4>(&[f32::NEG_INFINITY; 9]), Some(0));
+    assert_eq!(slice::argmin::<i8, 4>(&[i8::MAX; 9]), Some(0));
+}
@@ -0,0 +1,89 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{simd_sort, Simd};
+
+test_helpers::test_lanes! {
+    fn sort_lanes<const LANES: usize>() {
+        test_helpers::test_1(&|x: [i32; LANES]| {
+            let mut expected = x;
+            expected.sort();
+            proptest::prop_assert_eq!(Simd::from_array(x).sort_lanes().to_array(), expected);
+            Ok(())
+        });
+    }
+
+    fn sort_lanes_by_key<const LANES: usize>() {
+        test_helpers::test_1(&|x: [u8; LANES]| {
+            let payload: [usize; LANES] = core::array::from_fn(|i| i);
+            let (keys, payload) = Simd::from_array(x).sort_lanes_by_key(Simd::from_array(payload));
+            let mut expected = x;
+            expected.sort();
+            proptest::prop_assert_eq!(keys.to_array(), expected);
+
+            // Each payload still belongs to its key
+            let mut seen = [false; LANES];
+            for (key, index) in keys.to_array().into_iter().zip(payload.to_array()) {
+                proptest::prop_assert_eq!(x[index], key);
+                proptest::prop_assert!(!seen[index]);
+                seen[index] = true;
+            }
+            Ok(())
+        });
+    }
+
+    fn merge_sorted<const LANES: usize>() {
+        test_helpers::test_2(&|a: [i16; LANES], b: [i16; LANES]| {
+            let (mut a, mut b) = (a, b);
+            a.sort();
+            b.sort();
+            let mut expected = [a, b].concat();
+            expected.sort();
+            let (lo, hi) = Simd::merge_sorted(Simd::from_array(a), Simd::from_array(b));
+            proptest::prop_assert_eq!(&lo.to_array()[..], &expected[..LANES]);
+            proptest::prop_assert_eq!(&hi.to_array()[..], &expected[LANES..]);
+            Ok(())
+        });
+    }
+
+    fn simd_sort<const LANES: usize>() {
+        test_helpers::test_1(&|x: [u64; 16]| {
+            // Lengths around the vector sizes, and longer slices with many repeats
+            let mut data: Vec<u64> = x.iter().cycle().take(8 * LANES + 3).map(|x| x % 97).collect();
+            for len in [0, 1, LANES - 1, LANES, LANES + 1, 2 * LANES, 2 * LANES + 1, data.len()] {
+                let slice = &mut data[..len];
+                let mut expected = slice.to_vec();
+                expected.sort();
+                simd_sort::<_, LANES>(slice);
+                proptest::prop_assert_eq!(&slice[..], &expected[..]);
+            }
+            Ok(())
+        });
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn simd_sort_patterns() {
+    let n = 10_000;
+    let patterns: [Vec<i32>; 5] = [
+        (0..n).collect(),
+        (0..n).rev().collect(),
+        vec![7; n as usize],
+        (0..n).map(|x| x % 2).collect(),
+        (0..n)
+            .map(|x| x.wrapping_mul(0x9e3779b9u32 as i32))
+            .collect(),
+    ];
+    for mut data in patterns {
+        let mut expected = data.clone();
+        expected.sort();
+        simd_sort::<_, 8>(&mut data);
+        assert_eq!(data, expected);
+    }
+}
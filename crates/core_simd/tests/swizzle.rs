@@ -1,6 +1,8 @@
 
 #![feature(portable_simd)]
-use core_simd::simd::{Simd, Swizzle};
+use core_simd::simd::{
+    simd_swizzle, LaneCount, Mask, Simd, SupportedLaneCount, Swizzle, Swizzle2, Which,
+};
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen_test::*;
@@ -74,4 +76,139 @@ fn interleave_one() {
     let (even, odd) = lo.deinterleave(hi);
     assert_eq!(even, a);
     assert_eq!(odd, b);
-}
\ No newline at end of file
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn swizzle_mask() {
+    struct Index;
+    impl Swizzle<4, 4> for Index {
+        const INDEX: [usize; 4] = [2, 1, 3, 0];
+    }
+    impl Swizzle<4, 2> for Index {
+        const INDEX: [usize; 2] = [1, 1];
+    }
+
+    let mask = Mask::<i32, 4>::from_array([false, true, true, false]);
+    assert_eq!(
+        Index::swizzle_mask(mask).to_array(),
+        [true, true, false, false]
+    );
+    assert_eq!(Index::swizzle_mask(mask).to_array(), [true, true]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn swizzle2_mask() {
+    use Which::{First, Second};
+    struct Index;
+    impl Swizzle2<4, 8> for Index {
+        const INDEX: [Which; 8] = [
+            First(0),
+            Second(0),
+            First(1),
+            Second(1),
+            Second(3),
+            First(3),
+            Second(2),
+            First(2),
+        ];
+    }
+
+    let a = Mask::<i8, 4>::from_array([true, false, true, false]);
+    let b = Mask::<i8, 4>::from_array([false, false, true, true]);
+    assert_eq!(
+        Index::swizzle2_mask(a, b).to_array(),
+        [true, false, false, false, true, false, true, true]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn simd_swizzle_mask() {
+    use Which::{First, Second};
+    let a = Mask::<i16, 4>::from_array([true, false, false, true]);
+    let b = Mask::<i16, 4>::from_array([false, true, true, false]);
+
+    let r: Mask<i16, 4> = simd_swizzle!(a, [3, 2, 1, 0]);
+    assert_eq!(r, a.reverse());
+    let r: Mask<i16, 2> = simd_swizzle!(a, [0, 3]);
+    assert_eq!(r.to_array(), [true, true]);
+    let r: Mask<i16, 2> = simd_swizzle!(a, b, [First(1), Second(1)]);
+    assert_eq!(r.to_array(), [false, true]);
+
+    // Vectors still swizzle the same way
+    let v = Simd::from_array([1, 2, 3, 4]);
+    let r: Simd<i32, 2> = simd_swizzle!(v, [3, 0]);
+    assert_eq!(r.to_array(), [4, 1]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mask_lane_operations() {
+    let a = Mask::<i64, 4>::from_array([true, true, false, false]);
+    assert_eq!(a.reverse().to_array(), [false, false, true, true]);
+    assert_eq!(
+        a.rotate_lanes_left::<1>().to_array(),
+        [true, false, false, true]
+    );
+    assert_eq!(
+        a.rotate_lanes_right::<1>().to_array(),
+        [false, true, true, false]
+    );
+
+    let a = Mask::<i8, 64>::from_array(core::array::from_fn(|i| i % 3 == 0));
+    let b = Mask::<i8, 64>::from_array(core::array::from_fn(|i| i % 5 == 0));
+    assert_eq!(a.reverse().reverse(), a);
+    assert_eq!(a.rotate_lanes_left::<7>().rotate_lanes_right::<7>(), a);
+    assert_eq!(a.reverse().to_int(), a.to_int().reverse());
+    assert_eq!(
+        a.rotate_lanes_left::<7>().to_int(),
+        a.to_int().rotate_lanes_left::<7>()
+    );
+
+    let (lo, hi) = a.interleave(b);
+    let (lo_int, hi_int) = a.to_int().interleave(b.to_int());
+    assert_eq!(lo.to_int(), lo_int);
+    assert_eq!(hi.to_int(), hi_int);
+    let (even, odd) = lo.deinterleave(hi);
+    assert_eq!(even, a);
+    assert_eq!(odd, b);
+}
+
+fn transpose_scalar<const N: usize>(rows: [[u32; N]; N]) -> [[u32; N]; N] {
+    core::array::from_fn(|i| core::array::from_fn(|j| rows[j][i]))
+}
+
+fn check_transpose<const N: usize>()
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let rows: [[u32; N]; N] =
+        core::array::from_fn(|i| core::array::from_fn(|j| (i * N + j) as u32));
+    let transposed = Simd::transpose(rows.map(Simd::from_array));
+    assert_eq!(transposed.map(Simd::to_array), transpose_scalar(rows));
+    assert_eq!(Simd::transpose(transposed).map(Simd::to_array), rows);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn transpose() {
+    check_transpose::<1>();
+    check_transpose::<2>();
+    check_transpose::<4>();
+    check_transpose::<8>();
+    check_transpose::<16>();
+    check_transpose::<32>();
+    check_transpose::<64>();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn transpose_u8x16() {
+    let rows: [Simd<u8, 16>; 16] = core::array::from_fn(|i| Simd::splat(i as u8));
+    let columns = Simd::transpose(rows);
+    for column in columns {
+        assert_eq!(column.to_array(), core::array::from_fn(|i| i as u8));
+    }
+}
@@ -0,0 +1,37 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::Simd;
+
+test_helpers::test_lanes! {
+    fn swizzle_dyn<const LANES: usize>() {
+        test_helpers::test_2(&|table: [u8; LANES], idxs: [u8; LANES]| {
+            // Keep most indices in range, but cover the out of range ones too
+            let idxs = idxs.map(|idx| if idx < 224 { idx % LANES as u8 } else { idx });
+            let expected = idxs.map(|idx| table.get(usize::from(idx)).copied().unwrap_or(0));
+            proptest::prop_assert_eq!(
+                Simd::from_array(table).swizzle_dyn(Simd::from_array(idxs)).to_array(),
+                expected
+            );
+            Ok(())
+        });
+    }
+
+    fn swizzle_dyn_table<const LANES: usize>() {
+        test_helpers::test_2(&|table: [u8; 16], idxs: [u8; LANES]| {
+            let idxs = idxs.map(|idx| if idx < 224 { idx % 16 } else { idx });
+            let expected = idxs.map(|idx| table.get(usize::from(idx)).copied().unwrap_or(0));
+            let table = Simd::<u8, 16>::from_array(table);
+            proptest::prop_assert_eq!(
+                table.swizzle_dyn_table(Simd::from_array(idxs)).to_array(),
+                expected
+            );
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,59 @@
+#![feature(portable_simd, stdsimd)]
+#![cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use core_simd::simd::{
+    f64x2, f64x4, f64x8, mask16x8, mask32x16, mask64x2, mask8x64, Mask, ToBitMask,
+};
+
+#[test]
+fn f64_vectors() {
+    let v = f64x2::from_array([1., 2.]);
+    assert_eq!(f64x2::from(__m128d::from(v)), v);
+    let v = f64x4::from_array([1., 2., 3., 4.]);
+    assert_eq!(f64x4::from(__m256d::from(v)), v);
+    let v = f64x8::from_array([1., 2., 3., 4., 5., 6., 7., 8.]);
+    assert_eq!(f64x8::from(__m512d::from(v)), v);
+}
+
+#[test]
+fn full_width_masks() {
+    let mask = mask16x8::from_array([true, false, true, true, false, false, true, false]);
+    let vector = __m128i::from(mask);
+    assert_eq!(
+        core_simd::simd::i16x8::from(vector).to_array(),
+        [-1, 0, -1, -1, 0, 0, -1, 0]
+    );
+    assert_eq!(mask16x8::from(vector), mask);
+
+    // Only the sign bit is tested, as with `blendv`.
+    let vector = __m128i::from(core_simd::simd::i64x2::from_array([i64::MIN, i64::MAX]));
+    assert_eq!(mask64x2::from(vector).to_array(), [true, false]);
+}
+
+#[test]
+fn mmask() {
+    let mask = mask32x16::from_array([
+        true, false, false, false, true, true, false, false, false, false, false, false, false,
+        false, false, true,
+    ]);
+    let mmask: __mmask16 = mask.to_bitmask();
+    assert_eq!(mmask, 0b1000_0000_0011_0001);
+    assert_eq!(mask32x16::from_bitmask(mmask), mask);
+
+    let mmask: __mmask64 = 0x8000_0000_0000_0001;
+    let mask = mask8x64::from_bitmask(mmask);
+    assert!(mask.test(0) && mask.test(63));
+    assert!(!mask.test(1));
+    assert_eq!(mask.to_bitmask(), mmask);
+
+    let mask = Mask::<i16, 8>::from_bitmask(0b1010_0101u8 as __mmask8);
+    assert_eq!(
+        mask.to_array(),
+        [true, false, true, false, false, true, false, true]
+    );
+}
@@ -0,0 +1,384 @@
+//! Base64 encoding and decoding, as specified by [RFC 4648].
+//!
+//! [RFC 4648]: https://www.rfc-editor.org/rfc/rfc4648
+use crate::simd::{
+    LaneCount, Mask, Simd, SimdPartialEq, SimdPartialOrd, SimdUint, SupportedLaneCount, Swizzle,
+    Swizzle2, ToBitMask, Which,
+};
+
+/// The last two symbols of a base64 alphabet, which otherwise consists of `A-Z`, `a-z` and `0-9`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Alphabet {
+    symbol_62: u8,
+    symbol_63: u8,
+}
+
+impl Alphabet {
+    /// The standard alphabet, ending in `+` and `/`.
+    pub const STANDARD: Self = Self {
+        symbol_62: b'+',
+        symbol_63: b'/',
+    };
+
+    /// The URL and filename safe alphabet, ending in `-` and `_`.
+    pub const URL_SAFE: Self = Self {
+        symbol_62: b'-',
+        symbol_63: b'_',
+    };
+
+    #[inline]
+    fn encode(self, value: u8) -> u8 {
+        match value {
+            0..=25 => b'A' + value,
+            26..=51 => b'a' + value - 26,
+            52..=61 => b'0' + value - 52,
+            62 => self.symbol_62,
+            _ => self.symbol_63,
+        }
+    }
+
+    #[inline]
+    fn decode(self, symbol: u8) -> Option<u8> {
+        match symbol {
+            b'A'..=b'Z' => Some(symbol - b'A'),
+            b'a'..=b'z' => Some(symbol - b'a' + 26),
+            b'0'..=b'9' => Some(symbol - b'0' + 52),
+            _ if symbol == self.symbol_62 => Some(62),
+            _ if symbol == self.symbol_63 => Some(63),
+            _ => None,
+        }
+    }
+
+    /// Returns the offsets from each range of values to its symbols, indexed as in
+    /// [`Alphabet::encode_lanes`].
+    #[inline]
+    fn offsets(self) -> [u8; 16] {
+        let mut offsets = [0; 16];
+        offsets[0] = b'a' - 26;
+        for offset in &mut offsets[1..=10] {
+            *offset = b'0'.wrapping_sub(52);
+        }
+        offsets[11] = self.symbol_62.wrapping_sub(62);
+        offsets[12] = self.symbol_63.wrapping_sub(63);
+        offsets[13] = b'A';
+        offsets
+    }
+
+    /// Encodes each lane, which must be less than 64.
+    #[inline]
+    fn encode_lanes<const N: usize>(self, values: Simd<u8, N>) -> Simd<u8, N>
+    where
+        LaneCount<N>: SupportedLaneCount,
+    {
+        // 0 for `a-z`, 1 to 10 for each digit, 11 and 12 for the last two symbols, 13 for `A-Z`
+        let index = values
+            .simd_lt(Simd::splat(26))
+            .select(Simd::splat(13), values.saturating_sub(Simd::splat(51)));
+        values + Simd::<u8, 16>::from_array(self.offsets()).swizzle_dyn_table(index)
+    }
+
+    /// Decodes each lane, returning the values and a mask of invalid lanes.
+    #[inline]
+    fn decode_lanes<const N: usize>(self, symbols: Simd<u8, N>) -> (Simd<u8, N>, Mask<i8, N>)
+    where
+        LaneCount<N>: SupportedLaneCount,
+    {
+        let upper = symbols - Simd::splat(b'A');
+        let lower = symbols - Simd::splat(b'a');
+        let digit = symbols - Simd::splat(b'0');
+        let is_upper = upper.simd_lt(Simd::splat(26));
+        let is_lower = lower.simd_lt(Simd::splat(26));
+        let is_digit = digit.simd_lt(Simd::splat(10));
+        let is_62 = symbols.simd_eq(Simd::splat(self.symbol_62));
+        let is_63 = symbols.simd_eq(Simd::splat(self.symbol_63));
+
+        let values = is_upper.select(upper, Simd::splat(0))
+            | is_lower.select(lower + Simd::splat(26), Simd::splat(0))
+            | is_digit.select(digit + Simd::splat(52), Simd::splat(0))
+            | is_62.select(Simd::splat(62), Simd::splat(0))
+            | is_63.select(Simd::splat(63), Simd::splat(0));
+        let valid = is_upper | is_lower | is_digit | is_62 | is_63;
+        (values, !valid)
+    }
+}
+
+/// Spreads each group of 3 bytes over 4 lanes, selecting the bytes that make up the high bits
+/// (if `HIGH`) or low bits of each 6-bit value. Lanes without such a byte are taken from the
+/// second input.
+struct Spread<const HIGH: bool>;
+
+const fn spread_index<const HIGH: bool, const N: usize>() -> [Which; N] {
+    let mut index = [Which::Second(0); N];
+    let mut i = 0;
+    while i < N {
+        let (group, lane) = (i / 4, i % 4);
+        index[i] = match (HIGH, lane) {
+            (true, 0) | (false, 3) => Which::Second(0),
+            (true, _) => Which::First(3 * group + lane - 1),
+            (false, _) => Which::First(3 * group + lane),
+        };
+        i += 1;
+    }
+    index
+}
+
+impl<const HIGH: bool, const N: usize> Swizzle2<N, N> for Spread<HIGH> {
+    const INDEX: [Which; N] = spread_index::<HIGH, N>();
+}
+
+/// Gathers each group of 4 lanes into 3, selecting the lanes that make up the high bits (if
+/// `HIGH`) or low bits of each byte. The last quarter of the output is unused.
+struct Gather<const HIGH: bool>;
+
+const fn gather_index<const HIGH: bool, const N: usize>() -> [usize; N] {
+    let mut index = [0; N];
+    let mut i = 0;
+    while i < N / 4 * 3 {
+        index[i] = 4 * (i / 3) + i % 3 + !HIGH as usize;
+        i += 1;
+    }
+    index
+}
+
+impl<const HIGH: bool, const N: usize> Swizzle<N, N> for Gather<HIGH> {
+    const INDEX: [usize; N] = gather_index::<HIGH, N>();
+}
+
+/// Returns a vector repeating `pattern`.
+#[inline]
+fn repeat<const N: usize, const M: usize>(pattern: [u8; M]) -> Simd<u8, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    Simd::from_array(core::array::from_fn(|i| pattern[i % M]))
+}
+
+/// An error found while decoding base64.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The byte at this index of the input is not a symbol of the alphabet.
+    ///
+    /// This is also reported for the last symbol if it encodes bits that are not part of the
+    /// output, which have to be zero.
+    InvalidByte(usize),
+    /// The number of symbols is one more than a multiple of 4, which no input encodes to.
+    InvalidLength,
+    /// The input is not padded to a multiple of 4 symbols, and padding is required.
+    InvalidPadding,
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidByte(index) => write!(f, "invalid base64 symbol at index {}", index),
+            Self::InvalidLength => write!(f, "invalid base64 length"),
+            Self::InvalidPadding => write!(f, "invalid base64 padding"),
+        }
+    }
+}
+
+/// A base64 encoding, with an alphabet and a choice of padding.
+///
+/// Blocks of `N` symbols are encoded and decoded at a time, where `N` is a multiple of 4.
+/// Other lane counts encode and decode one symbol at a time.
+///
+/// ```
+/// # use std_codec::base64::{Base64, DecodeError};
+/// let mut encoded = [0; 32];
+/// let encoded = Base64::STANDARD.encode_to_slice::<16>(b"portable simd?", &mut encoded);
+/// assert_eq!(encoded, "cG9ydGFibGUgc2ltZD8=");
+///
+/// let mut decoded = [0; 32];
+/// let decoded = Base64::STANDARD.decode_to_slice::<16>(encoded.as_bytes(), &mut decoded);
+/// assert_eq!(decoded, Ok(&b"portable simd?"[..]));
+///
+/// let mut decoded = [0; 32];
+/// let error = Base64::URL_SAFE.decode_to_slice::<16>(b"cG9ydGFibGUgc2ltZD+=", &mut decoded);
+/// assert_eq!(error, Err(DecodeError::InvalidByte(18)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Base64 {
+    alphabet: Alphabet,
+    padding: bool,
+}
+
+impl Base64 {
+    /// The standard alphabet, with padding.
+    pub const STANDARD: Self = Self::new(Alphabet::STANDARD, true);
+
+    /// The standard alphabet, without padding.
+    pub const STANDARD_NO_PAD: Self = Self::new(Alphabet::STANDARD, false);
+
+    /// The URL and filename safe alphabet, with padding.
+    pub const URL_SAFE: Self = Self::new(Alphabet::URL_SAFE, true);
+
+    /// The URL and filename safe alphabet, without padding.
+    pub const URL_SAFE_NO_PAD: Self = Self::new(Alphabet::URL_SAFE, false);
+
+    /// Creates an encoding with the given alphabet.
+    ///
+    /// If `padding` is true, the output is padded to a multiple of 4 symbols with `=`, and
+    /// padding is required when decoding. Otherwise padding is neither written nor accepted.
+    #[inline]
+    pub const fn new(alphabet: Alphabet, padding: bool) -> Self {
+        Self { alphabet, padding }
+    }
+
+    /// Returns the length of the encoding of `len` bytes.
+    #[inline]
+    pub const fn encoded_len(&self, len: usize) -> usize {
+        if self.padding {
+            (len + 2) / 3 * 4
+        } else {
+            len / 3 * 4 + (len % 3 * 4 + 2) / 3
+        }
+    }
+
+    /// Returns an upper bound on the length of the decoding of `len` symbols.
+    #[inline]
+    pub const fn decoded_len_max(&self, len: usize) -> usize {
+        (len + 3) / 4 * 3
+    }
+
+    /// Encodes `input` into the start of `output`, `N` symbols at a time.
+    ///
+    /// Returns the encoded part of `output`.
+    ///
+    /// # Panics
+    /// Panics if `output` is shorter than [`Base64::encoded_len`] of the length of `input`.
+    #[inline]
+    pub fn encode_to_slice<'a, const N: usize>(&self, input: &[u8], output: &'a mut [u8]) -> &'a str
+    where
+        LaneCount<N>: SupportedLaneCount,
+    {
+        let output = &mut output[..self.encoded_len(input.len())];
+        let mut read = 0;
+        let mut written = 0;
+
+        // Each block reads `N` bytes, but only encodes the first three quarters of them
+        if N % 4 == 0 {
+            let high_shift = repeat([0, 4, 2, 0]);
+            let low_shift = repeat([2, 4, 6, 0]);
+            while read + N <= input.len() {
+                let bytes = Simd::<u8, N>::from_slice(&input[read..]);
+                let high = Spread::<true>::swizzle2(bytes, Simd::splat(0));
+                let low = Spread::<false>::swizzle2(bytes, Simd::splat(0));
+                let values = (high << high_shift | low >> low_shift) & Simd::splat(0x3f);
+                output[written..written + N]
+                    .copy_from_slice(self.alphabet.encode_lanes(values).as_array());
+                read += N / 4 * 3;
+                written += N;
+            }
+        }
+
+        for group in input[read..].chunks(3) {
+            let bytes = [
+                group[0],
+                group.get(1).copied().unwrap_or(0),
+                group.get(2).copied().unwrap_or(0),
+            ];
+            let values = [
+                bytes[0] >> 2,
+                (bytes[0] << 4 | bytes[1] >> 4) & 0x3f,
+                (bytes[1] << 2 | bytes[2] >> 6) & 0x3f,
+                bytes[2] & 0x3f,
+            ];
+            for value in &values[..group.len() + 1] {
+                output[written] = self.alphabet.encode(*value);
+                written += 1;
+            }
+        }
+        for padding in &mut output[written..] {
+            *padding = b'=';
+        }
+
+        // Safety: every byte of the output is ASCII
+        unsafe { core::str::from_utf8_unchecked(output) }
+    }
+
+    /// Decodes `input` into the start of `output`, `N` symbols at a time.
+    ///
+    /// Returns the decoded part of `output`.
+    ///
+    /// # Panics
+    /// Panics if `output` is shorter than [`Base64::decoded_len_max`] of the length of `input`.
+    #[inline]
+    pub fn decode_to_slice<'a, const N: usize>(
+        &self,
+        input: &[u8],
+        output: &'a mut [u8],
+    ) -> Result<&'a [u8], DecodeError>
+    where
+        LaneCount<N>: SupportedLaneCount,
+        Mask<i8, N>: ToBitMask,
+        <Mask<i8, N> as ToBitMask>::BitMask: Into<u64>,
+    {
+        let output = &mut output[..self.decoded_len_max(input.len())];
+        let symbols = if self.padding {
+            if input.len() % 4 != 0 {
+                return Err(DecodeError::InvalidPadding);
+            }
+            let padding = input
+                .iter()
+                .rev()
+                .take(2)
+                .take_while(|&&b| b == b'=')
+                .count();
+            &input[..input.len() - padding]
+        } else {
+            input
+        };
+        if symbols.len() % 4 == 1 {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        let mut read = 0;
+        let mut written = 0;
+
+        // Each block writes `N` bytes, but only the first three quarters of them are decoded
+        if N % 4 == 0 {
+            let high_shift = repeat([2, 4, 6]);
+            let low_shift = repeat([4, 2, 0]);
+            while read + N <= symbols.len() && written + N <= output.len() {
+                let block = Simd::<u8, N>::from_slice(&symbols[read..]);
+                let (values, invalid) = self.alphabet.decode_lanes(block);
+                let invalid: u64 = invalid.to_bitmask().into();
+                if invalid != 0 {
+                    return Err(DecodeError::InvalidByte(
+                        read + invalid.trailing_zeros() as usize,
+                    ));
+                }
+                let high = Gather::<true>::swizzle(values);
+                let low = Gather::<false>::swizzle(values);
+                let bytes = high << high_shift | low >> low_shift;
+                output[written..written + N].copy_from_slice(bytes.as_array());
+                read += N;
+                written += N / 4 * 3;
+            }
+        }
+
+        for group in symbols[read..].chunks(4) {
+            let mut values = [0; 4];
+            for (i, symbol) in group.iter().enumerate() {
+                values[i] = self
+                    .alphabet
+                    .decode(*symbol)
+                    .ok_or(DecodeError::InvalidByte(read + i))?;
+            }
+            let bytes = [
+                values[0] << 2 | values[1] >> 4,
+                values[1] << 4 | values[2] >> 2,
+                values[2] << 6 | values[3],
+            ];
+            let len = group.len() - 1;
+            // The bits of the last symbol that are not part of the output must be zero
+            if len < 3 && bytes[len] != 0 {
+                return Err(DecodeError::InvalidByte(read + len));
+            }
+            output[written..written + len].copy_from_slice(&bytes[..len]);
+            read += group.len();
+            written += len;
+        }
+        Ok(&output[..written])
+    }
+}
@@ -0,0 +1,318 @@
+//! Adler-32, Fletcher and CRC-32 checksums.
+//!
+//! The Adler-32 and Fletcher sums are computed `N` bytes at a time: each block is widened to
+//! 32-bit lanes and added to lanewise running sums, which are combined with a horizontal sum at
+//! the end of each run of blocks.
+//!
+//! The CRCs fold 16 bytes at a time with [`SimdUint::carryless_mul`], and need no lookup
+//! tables.
+use crate::simd::{LaneCount, Simd, SimdUint, SupportedLaneCount};
+
+/// Adds the words of `data` to the Fletcher sums `a` and `b`, modulo `modulus`, `N` words at a
+/// time.
+///
+/// Words are single bytes, or little-endian pairs of bytes if `WIDE`, in which case an odd final
+/// byte is padded with zero.
+#[inline]
+fn fletcher_sums<const N: usize, const WIDE: bool>(
+    mut a: u32,
+    mut b: u32,
+    data: &[u8],
+    modulus: u32,
+) -> (u32, u32)
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let word_bytes = if WIDE { 2 } else { 1 };
+    // The most blocks that can be summed before a lane of `weighted` could overflow
+    let max_blocks = if WIDE { 360 } else { 5552 };
+    let positions = Simd::<u64, N>::from_array(core::array::from_fn(|i| i as u64));
+    let modulus = u64::from(modulus);
+
+    let mut blocks = data.chunks_exact(N * word_bytes);
+    loop {
+        // For each lane, the sum of its words, and the sum of the running sums after each block
+        let mut sum = Simd::<u32, N>::splat(0);
+        let mut weighted = Simd::<u32, N>::splat(0);
+        let mut count = 0;
+        for block in blocks.by_ref().take(max_blocks) {
+            let words = if WIDE {
+                let (low, high) = Simd::<u8, N>::from_slice(&block[..N])
+                    .deinterleave(Simd::from_slice(&block[N..]));
+                low.cast::<u32>() | high.cast::<u32>() << Simd::splat(8)
+            } else {
+                Simd::<u8, N>::from_slice(block).cast::<u32>()
+            };
+            sum += words;
+            weighted += sum;
+            count += 1;
+        }
+        if count == 0 {
+            break;
+        }
+
+        // Word `i` of block `t` of `count` is added to `b` once for each remaining word,
+        // `(count - t) * N - i` times
+        let len = (count * N) as u64;
+        let sum = sum.cast::<u64>();
+        let offsets = (sum * positions).reduce_sum() % modulus;
+        let weighted = weighted.cast::<u64>().reduce_sum() * N as u64;
+        b = ((u64::from(b) + len * u64::from(a) + weighted + modulus - offsets) % modulus) as u32;
+        a = ((u64::from(a) + sum.reduce_sum()) % modulus) as u32;
+    }
+
+    let modulus = modulus as u32;
+    for word in blocks.remainder().chunks(word_bytes) {
+        let word = if WIDE {
+            u32::from(word[0]) | u32::from(word.get(1).copied().unwrap_or(0)) << 8
+        } else {
+            u32::from(word[0])
+        };
+        a = (a + word) % modulus;
+        b = (b + a) % modulus;
+    }
+    (a, b)
+}
+
+/// Computes the Adler-32 checksum of `data`, `N` bytes at a time.
+///
+/// ```
+/// # use std_codec::checksum;
+/// assert_eq!(checksum::adler32::<32>(b"Wikipedia"), 0x11e6_0398);
+/// ```
+#[inline]
+#[must_use]
+pub fn adler32<const N: usize>(data: &[u8]) -> u32
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    adler32_update::<N>(1, data)
+}
+
+/// Updates the Adler-32 checksum `adler` of some data with the bytes of `data` that follow it,
+/// `N` bytes at a time.
+///
+/// The checksum of no data is 1.
+///
+/// ```
+/// # use std_codec::checksum;
+/// let adler = checksum::adler32::<32>(b"Wiki");
+/// assert_eq!(checksum::adler32_update::<32>(adler, b"pedia"), checksum::adler32::<32>(b"Wikipedia"));
+/// ```
+#[inline]
+#[must_use]
+pub fn adler32_update<const N: usize>(adler: u32, data: &[u8]) -> u32
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let (a, b) = fletcher_sums::<N, false>(adler & 0xffff, adler >> 16, data, 65521);
+    b << 16 | a
+}
+
+/// Computes the Fletcher-16 checksum of `data`, `N` bytes at a time.
+///
+/// ```
+/// # use std_codec::checksum;
+/// assert_eq!(checksum::fletcher16::<32>(b"abcde"), 0xc8f0);
+/// ```
+#[inline]
+#[must_use]
+pub fn fletcher16<const N: usize>(data: &[u8]) -> u16
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let (a, b) = fletcher_sums::<N, false>(0, 0, data, 255);
+    (b << 8 | a) as u16
+}
+
+/// Computes the Fletcher-32 checksum of `data`, `N` 16-bit words at a time.
+///
+/// Words are read in little-endian order. If `data` has an odd length, its last byte is padded
+/// with zero.
+///
+/// ```
+/// # use std_codec::checksum;
+/// assert_eq!(checksum::fletcher32::<16>(b"abcde"), 0xf04f_c729);
+/// ```
+#[inline]
+#[must_use]
+pub fn fletcher32<const N: usize>(data: &[u8]) -> u32
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let (a, b) = fletcher_sums::<N, true>(0, 0, data, 65535);
+    b << 16 | a
+}
+
+/// Multiplies the lanes of `acc` by the same lanes of `keys` without carries, and adds the
+/// products.
+#[inline]
+fn fold(acc: Simd<u64, 2>, keys: Simd<u64, 2>) -> Simd<u64, 2> {
+    let (low, high) = acc.carryless_mul(keys);
+    Simd::from_array([low[0] ^ low[1], high[0] ^ high[1]])
+}
+
+/// Loads 16 bytes as a little-endian 128-bit polynomial, in two 64-bit lanes.
+#[inline]
+fn load(bytes: &[u8]) -> Simd<u64, 2> {
+    let (low, high) = bytes.split_at(8);
+    Simd::from_array([
+        u64::from_le_bytes(low.try_into().unwrap()),
+        u64::from_le_bytes(high[..8].try_into().unwrap()),
+    ])
+}
+
+/// Returns `x^n mod poly`, where `poly` includes its `x^32` term.
+const fn pow_mod(n: u32, poly: u64) -> u64 {
+    let mut remainder = 1;
+    let mut i = 0;
+    while i < n {
+        remainder <<= 1;
+        if remainder & 1 << 32 != 0 {
+            remainder ^= poly;
+        }
+        i += 1;
+    }
+    remainder
+}
+
+/// A reflected 32-bit CRC, and the keys for folding it.
+///
+/// The bytes of the message are a polynomial with the first bit as its highest coefficient, so
+/// 128-bit blocks load as bit-reversed polynomials. The carry-less product of two bit-reversed
+/// 64-bit polynomials is a bit-reversed 127-bit polynomial, which is one bit short of 128 bits,
+/// so each key is the power of `x` it stands for divided by `x`.
+struct Crc {
+    /// The polynomial without its `x^32` term, bit-reversed.
+    reflected: u32,
+    /// Keys to move a 128-bit block forward by 128 bits.
+    fold_128: Simd<u64, 2>,
+    /// Keys to move a 128-bit block forward by 512 bits.
+    fold_512: Simd<u64, 2>,
+}
+
+impl Crc {
+    const fn new(poly: u64) -> Self {
+        Self {
+            reflected: (poly as u32).reverse_bits(),
+            fold_128: Self::keys(128, poly),
+            fold_512: Self::keys(512, poly),
+        }
+    }
+
+    /// Returns the keys to multiply the high and low halves of a block by `x^distance`.
+    ///
+    /// The high half of the polynomial is the low lane, and is multiplied by an extra `x^64`.
+    const fn keys(distance: u32, poly: u64) -> Simd<u64, 2> {
+        Simd::from_array([
+            pow_mod(distance + 64 - 1, poly).reverse_bits(),
+            pow_mod(distance - 1, poly).reverse_bits(),
+        ])
+    }
+
+    /// Updates the CRC register `crc` one bit at a time.
+    #[inline]
+    fn bitwise(&self, mut crc: u32, data: &[u8]) -> u32 {
+        for &byte in data {
+            crc ^= u32::from(byte);
+            for _ in 0..8 {
+                crc = crc >> 1 ^ (self.reflected & (crc & 1).wrapping_neg());
+            }
+        }
+        crc
+    }
+
+    #[inline]
+    fn update(&self, crc: u32, mut data: &[u8]) -> u32 {
+        let mut crc = !crc;
+        if data.len() >= 16 {
+            // The register is added to the start of the message, which is then reduced as is
+            let mut acc = load(data) ^ Simd::from_array([u64::from(crc), 0]);
+            data = &data[16..];
+
+            if data.len() >= 48 {
+                // Fold four independent accumulators 64 bytes at a time, then into each other
+                let mut accs = [acc, load(data), load(&data[16..]), load(&data[32..])];
+                let mut blocks = data[48..].chunks_exact(64);
+                for block in blocks.by_ref() {
+                    for (acc, block) in accs.iter_mut().zip(block.chunks_exact(16)) {
+                        *acc = fold(*acc, self.fold_512) ^ load(block);
+                    }
+                }
+                data = blocks.remainder();
+                acc = accs[1..]
+                    .iter()
+                    .fold(accs[0], |acc, &next| fold(acc, self.fold_128) ^ next);
+            }
+
+            let mut blocks = data.chunks_exact(16);
+            for block in blocks.by_ref() {
+                acc = fold(acc, self.fold_128) ^ load(block);
+            }
+            data = blocks.remainder();
+
+            let mut bytes = [0; 16];
+            bytes[..8].copy_from_slice(&acc[0].to_le_bytes());
+            bytes[8..].copy_from_slice(&acc[1].to_le_bytes());
+            crc = self.bitwise(0, &bytes);
+        }
+        !self.bitwise(crc, data)
+    }
+}
+
+const CRC32: Crc = Crc::new(0x1_04c1_1db7);
+const CRC32C: Crc = Crc::new(0x1_1edc_6f41);
+
+/// Computes the CRC-32 (ISO-HDLC, as used by zlib and PNG) of `data`.
+///
+/// ```
+/// # use std_codec::checksum;
+/// assert_eq!(checksum::crc32(b"123456789"), 0xcbf4_3926);
+/// ```
+#[inline]
+#[must_use]
+pub fn crc32(data: &[u8]) -> u32 {
+    crc32_update(0, data)
+}
+
+/// Updates the CRC-32 `crc` of some data with the bytes of `data` that follow it.
+///
+/// The CRC of no data is 0.
+///
+/// ```
+/// # use std_codec::checksum;
+/// let crc = checksum::crc32(b"1234");
+/// assert_eq!(checksum::crc32_update(crc, b"56789"), checksum::crc32(b"123456789"));
+/// ```
+#[inline]
+#[must_use]
+pub fn crc32_update(crc: u32, data: &[u8]) -> u32 {
+    CRC32.update(crc, data)
+}
+
+/// Computes the CRC-32C (Castagnoli, as used by iSCSI and ext4) of `data`.
+///
+/// ```
+/// # use std_codec::checksum;
+/// assert_eq!(checksum::crc32c(b"123456789"), 0xe306_9283);
+/// ```
+#[inline]
+#[must_use]
+pub fn crc32c(data: &[u8]) -> u32 {
+    crc32c_update(0, data)
+}
+
+/// Updates the CRC-32C `crc` of some data with the bytes of `data` that follow it.
+///
+/// The CRC of no data is 0.
+///
+/// ```
+/// # use std_codec::checksum;
+/// let crc = checksum::crc32c(b"1234");
+/// assert_eq!(checksum::crc32c_update(crc, b"56789"), checksum::crc32c(b"123456789"));
+/// ```
+#[inline]
+#[must_use]
+pub fn crc32c_update(crc: u32, data: &[u8]) -> u32 {
+    CRC32C.update(crc, data)
+}
@@ -0,0 +1,178 @@
+//! Hexadecimal encoding and decoding.
+use crate::simd::{LaneCount, Mask, Simd, SimdPartialOrd, SupportedLaneCount, ToBitMask};
+
+const LOWER: [u8; 16] = *b"0123456789abcdef";
+const UPPER: [u8; 16] = *b"0123456789ABCDEF";
+
+/// An error found while decoding hex.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The byte at this index of the input is not a hex digit.
+    InvalidByte(usize),
+    /// The input has an odd number of digits.
+    OddLength,
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidByte(index) => write!(f, "invalid hex digit at index {}", index),
+            Self::OddLength => write!(f, "odd number of hex digits"),
+        }
+    }
+}
+
+#[inline]
+fn encode_with<'a, const N: usize>(table: [u8; 16], input: &[u8], output: &'a mut [u8]) -> &'a str
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let output = &mut output[..input.len() * 2];
+    let mut inputs = input.chunks_exact(N);
+    let mut outputs = output.chunks_exact_mut(2 * N);
+    let digits = Simd::<u8, 16>::from_array(table);
+    for (input, output) in inputs.by_ref().zip(outputs.by_ref()) {
+        let input = Simd::<u8, N>::from_slice(input);
+        let high = digits.swizzle_dyn_table(input >> Simd::splat(4));
+        let low = digits.swizzle_dyn_table(input & Simd::splat(0x0f));
+        let (first, second) = high.interleave(low);
+        output[..N].copy_from_slice(first.as_array());
+        output[N..].copy_from_slice(second.as_array());
+    }
+    for (input, output) in inputs
+        .remainder()
+        .iter()
+        .zip(outputs.into_remainder().chunks_exact_mut(2))
+    {
+        output[0] = table[usize::from(input >> 4)];
+        output[1] = table[usize::from(input & 0x0f)];
+    }
+
+    // Safety: every byte of the output is an ASCII hex digit
+    unsafe { core::str::from_utf8_unchecked(output) }
+}
+
+/// Encodes `input` as lower case hex into the start of `output`, `N` bytes at a time.
+///
+/// Returns the encoded part of `output`.
+///
+/// # Panics
+/// Panics if `output` is shorter than twice the length of `input`.
+///
+/// ```
+/// # use std_codec::hex;
+/// let mut output = [0; 16];
+/// assert_eq!(hex::encode_to_slice::<4>(b"\x00\x7f\xab\xff\x10", &mut output), "007fabff10");
+/// ```
+#[inline]
+pub fn encode_to_slice<'a, const N: usize>(input: &[u8], output: &'a mut [u8]) -> &'a str
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    encode_with::<N>(LOWER, input, output)
+}
+
+/// Encodes `input` as upper case hex into the start of `output`, `N` bytes at a time.
+///
+/// Returns the encoded part of `output`.
+///
+/// # Panics
+/// Panics if `output` is shorter than twice the length of `input`.
+///
+/// ```
+/// # use std_codec::hex;
+/// let mut output = [0; 16];
+/// assert_eq!(hex::encode_upper_to_slice::<4>(b"\x00\x7f\xab\xff\x10", &mut output), "007FABFF10");
+/// ```
+#[inline]
+pub fn encode_upper_to_slice<'a, const N: usize>(input: &[u8], output: &'a mut [u8]) -> &'a str
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    encode_with::<N>(UPPER, input, output)
+}
+
+/// Decodes each lane from a hex digit, returning the digit values and a mask of invalid lanes.
+#[inline]
+fn decode_digits<const N: usize>(digits: Simd<u8, N>) -> (Simd<u8, N>, Mask<i8, N>)
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let decimal = digits - Simd::splat(b'0');
+    let is_decimal = decimal.simd_lt(Simd::splat(10));
+    // Setting bit 5 maps upper case letters to lower case
+    let letter = (digits | Simd::splat(0x20)) - Simd::splat(b'a');
+    let is_letter = letter.simd_lt(Simd::splat(6));
+    let values = is_decimal.select(decimal, letter + Simd::splat(10));
+    (values, !(is_decimal | is_letter))
+}
+
+#[inline]
+fn decode_digit(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|value| value as u8)
+}
+
+/// Decodes hex from `input` into the start of `output`, `N` bytes at a time.
+///
+/// Both upper and lower case digits are accepted. Returns the decoded part of `output`.
+///
+/// # Panics
+/// Panics if `output` is shorter than half the length of `input`.
+///
+/// ```
+/// # use std_codec::hex::{self, DecodeError};
+/// let mut output = [0; 8];
+/// assert_eq!(hex::decode_to_slice::<4>(b"007fABff10", &mut output), Ok(&b"\x00\x7f\xab\xff\x10"[..]));
+/// assert_eq!(hex::decode_to_slice::<4>(b"007fABfg10", &mut output), Err(DecodeError::InvalidByte(7)));
+/// assert_eq!(hex::decode_to_slice::<4>(b"007", &mut output), Err(DecodeError::OddLength));
+/// ```
+#[inline]
+pub fn decode_to_slice<'a, const N: usize>(
+    input: &[u8],
+    output: &'a mut [u8],
+) -> Result<&'a [u8], DecodeError>
+where
+    LaneCount<N>: SupportedLaneCount,
+    Mask<i8, N>: ToBitMask,
+    <Mask<i8, N> as ToBitMask>::BitMask: Into<u64>,
+{
+    if input.len() % 2 != 0 {
+        return Err(DecodeError::OddLength);
+    }
+    let output = &mut output[..input.len() / 2];
+
+    let mut inputs = input.chunks_exact(2 * N);
+    let mut outputs = output.chunks_exact_mut(N);
+    for (block, (input, output)) in inputs.by_ref().zip(outputs.by_ref()).enumerate() {
+        let (first, first_invalid) = decode_digits(Simd::<u8, N>::from_slice(&input[..N]));
+        let (second, second_invalid) = decode_digits(Simd::<u8, N>::from_slice(&input[N..]));
+        let invalid: u64 = first_invalid.to_bitmask().into();
+        if invalid != 0 {
+            return Err(DecodeError::InvalidByte(
+                block * 2 * N + invalid.trailing_zeros() as usize,
+            ));
+        }
+        let invalid: u64 = second_invalid.to_bitmask().into();
+        if invalid != 0 {
+            return Err(DecodeError::InvalidByte(
+                block * 2 * N + N + invalid.trailing_zeros() as usize,
+            ));
+        }
+        let (high, low) = first.deinterleave(second);
+        output.copy_from_slice((high << Simd::splat(4) | low).as_array());
+    }
+
+    let offset = input.len() - inputs.remainder().len();
+    for (pair, (input, output)) in inputs
+        .remainder()
+        .chunks_exact(2)
+        .zip(outputs.into_remainder())
+        .enumerate()
+    {
+        let index = offset + 2 * pair;
+        let high = decode_digit(input[0]).ok_or(DecodeError::InvalidByte(index))?;
+        let low = decode_digit(input[1]).ok_or(DecodeError::InvalidByte(index + 1))?;
+        *output = high << 4 | low;
+    }
+    Ok(output)
+}
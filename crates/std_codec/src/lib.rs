@@ -0,0 +1,12 @@
+#![no_std]
+#![feature(portable_simd)]
+//! Base64 and hex encoding and decoding, and checksums, built on portable SIMD.
+//!
+//! Most functions are generic over the number of bytes `N` processed at once. Bytes are mapped
+//! to and from their text representation lanewise, and regrouped with swizzles. Decoders check
+//! every lane for invalid input, and report the position of the first invalid byte.
+use core_simd::simd;
+
+pub mod base64;
+pub mod checksum;
+pub mod hex;
@@ -0,0 +1,202 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{Mask, ToBitMask};
+use std_codec::base64::{Base64, DecodeError};
+
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+const ENCODINGS: [(Base64, &[u8; 64], bool); 4] = [
+    (Base64::STANDARD, STANDARD, true),
+    (Base64::STANDARD_NO_PAD, STANDARD, false),
+    (Base64::URL_SAFE, URL_SAFE, true),
+    (Base64::URL_SAFE_NO_PAD, URL_SAFE, false),
+];
+
+/// Encodes base64 one bit at a time.
+fn encode_scalar(alphabet: &[u8; 64], padding: bool, input: &[u8]) -> String {
+    let bits: Vec<bool> = input
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |bit| byte >> bit & 1 == 1))
+        .collect();
+    let mut encoded: String = bits
+        .chunks(6)
+        .map(|symbol| {
+            let value = (0..6).fold(0, |value, i| {
+                value << 1 | *symbol.get(i).unwrap_or(&false) as usize
+            });
+            alphabet[value] as char
+        })
+        .collect();
+    while padding && encoded.len() % 4 != 0 {
+        encoded.push('=');
+    }
+    encoded
+}
+
+/// Decodes base64 one symbol at a time.
+fn decode_scalar(alphabet: &[u8; 64], padding: bool, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut symbols = input;
+    if padding {
+        if input.len() % 4 != 0 {
+            return Err(DecodeError::InvalidPadding);
+        }
+        while symbols.len() + 2 > input.len() && symbols.last() == Some(&b'=') {
+            symbols = &symbols[..symbols.len() - 1];
+        }
+    }
+    if symbols.len() % 4 == 1 {
+        return Err(DecodeError::InvalidLength);
+    }
+    let mut bits = Vec::new();
+    for (index, symbol) in symbols.iter().enumerate() {
+        let value = alphabet
+            .iter()
+            .position(|s| s == symbol)
+            .ok_or(DecodeError::InvalidByte(index))?;
+        bits.extend((0..6).rev().map(|bit| value >> bit & 1 == 1));
+    }
+    // Leftover bits must be zero
+    if bits[bits.len() / 8 * 8..].iter().any(|&bit| bit) {
+        return Err(DecodeError::InvalidByte(symbols.len() - 1));
+    }
+    Ok(bits
+        .chunks_exact(8)
+        .map(|byte| byte.iter().fold(0, |acc, &bit| acc << 1 | bit as u8))
+        .collect())
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rfc_4648_vectors() {
+    let vectors: [(&[u8], &str); 7] = [
+        (b"", ""),
+        (b"f", "Zg=="),
+        (b"fo", "Zm8="),
+        (b"foo", "Zm9v"),
+        (b"foob", "Zm9vYg=="),
+        (b"fooba", "Zm9vYmE="),
+        (b"foobar", "Zm9vYmFy"),
+    ];
+    for (decoded, encoded) in vectors {
+        let mut output = [0; 16];
+        assert_eq!(
+            Base64::STANDARD.encode_to_slice::<4>(decoded, &mut output),
+            encoded
+        );
+        let mut output = [0; 16];
+        assert_eq!(
+            Base64::STANDARD.decode_to_slice::<4>(encoded.as_bytes(), &mut output),
+            Ok(decoded)
+        );
+
+        let unpadded = encoded.trim_end_matches('=');
+        let mut output = [0; 16];
+        assert_eq!(
+            Base64::URL_SAFE_NO_PAD.encode_to_slice::<4>(decoded, &mut output),
+            unpadded
+        );
+        let mut output = [0; 16];
+        assert_eq!(
+            Base64::URL_SAFE_NO_PAD.decode_to_slice::<4>(unpadded.as_bytes(), &mut output),
+            Ok(decoded)
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn invalid_padding() {
+    let mut output = [0; 16];
+    assert_eq!(
+        Base64::STANDARD.decode_to_slice::<4>(b"Zg", &mut output),
+        Err(DecodeError::InvalidPadding)
+    );
+    assert_eq!(
+        Base64::STANDARD.decode_to_slice::<4>(b"Zg=", &mut output),
+        Err(DecodeError::InvalidPadding)
+    );
+    assert_eq!(
+        Base64::STANDARD.decode_to_slice::<4>(b"Z===", &mut output),
+        Err(DecodeError::InvalidByte(1))
+    );
+    assert_eq!(
+        Base64::STANDARD.decode_to_slice::<4>(b"Zg==Zg==", &mut output),
+        Err(DecodeError::InvalidByte(2))
+    );
+    assert_eq!(
+        Base64::STANDARD_NO_PAD.decode_to_slice::<4>(b"Zg==", &mut output),
+        Err(DecodeError::InvalidByte(2))
+    );
+    assert_eq!(
+        Base64::STANDARD_NO_PAD.decode_to_slice::<4>(b"Zm9vY", &mut output),
+        Err(DecodeError::InvalidLength)
+    );
+    assert_eq!(
+        Base64::STANDARD.decode_to_slice::<4>(b"Zh==", &mut output),
+        Err(DecodeError::InvalidByte(1))
+    );
+}
+
+test_helpers::test_lanes! {
+    fn roundtrip<const LANES: usize>()
+    where
+        Mask<i8, LANES>: ToBitMask,
+        <Mask<i8, LANES> as ToBitMask>::BitMask: Into<u64>,
+    {
+        test_helpers::test_2(&|input: [u8; 100], len: u8| {
+            let input = &input[..usize::from(len) % 101];
+            for (base64, alphabet, padding) in ENCODINGS {
+                let mut encoded = [0; 136];
+                let encoded = base64.encode_to_slice::<LANES>(input, &mut encoded);
+                proptest::prop_assert_eq!(encoded, encode_scalar(alphabet, padding, input));
+                proptest::prop_assert_eq!(encoded.len(), base64.encoded_len(input.len()));
+
+                let mut decoded = [0; 102];
+                proptest::prop_assert_eq!(
+                    base64.decode_to_slice::<LANES>(encoded.as_bytes(), &mut decoded),
+                    Ok(input)
+                );
+            }
+            Ok(())
+        });
+    }
+
+    fn decode_matches_scalar<const LANES: usize>()
+    where
+        Mask<i8, LANES>: ToBitMask,
+        <Mask<i8, LANES> as ToBitMask>::BitMask: Into<u64>,
+    {
+        test_helpers::test_3(&|input: [u8; 100], corrupt: [u8; 100], len: u8| {
+            for (base64, alphabet, padding) in ENCODINGS {
+                // Mostly symbols of the alphabet, with the occasional invalid byte
+                let input: Vec<u8> = input
+                    .iter()
+                    .zip(corrupt)
+                    .map(|(&byte, corrupt)| {
+                        if corrupt < 252 {
+                            alphabet[usize::from(byte) % 64]
+                        } else {
+                            byte
+                        }
+                    })
+                    .take(usize::from(len) % 101)
+                    .collect();
+                let mut decoded = [0; 75];
+                proptest::prop_assert_eq!(
+                    base64
+                        .decode_to_slice::<LANES>(&input, &mut decoded)
+                        .map(<[u8]>::to_vec),
+                    decode_scalar(alphabet, padding, &input)
+                );
+            }
+            Ok(())
+        });
+    }
+}
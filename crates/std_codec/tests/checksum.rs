@@ -0,0 +1,129 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use std_codec::checksum;
+
+/// Computes Fletcher sums one word at a time.
+fn fletcher_scalar(a: u32, b: u32, words: impl Iterator<Item = u32>, modulus: u32) -> (u32, u32) {
+    words.fold((a, b), |(a, b), word| {
+        let a = (a + word) % modulus;
+        (a, (b + a) % modulus)
+    })
+}
+
+fn adler32_scalar(data: &[u8]) -> u32 {
+    let (a, b) = fletcher_scalar(1, 0, data.iter().map(|&byte| byte.into()), 65521);
+    b << 16 | a
+}
+
+fn fletcher16_scalar(data: &[u8]) -> u16 {
+    let (a, b) = fletcher_scalar(0, 0, data.iter().map(|&byte| byte.into()), 255);
+    (b << 8 | a) as u16
+}
+
+fn fletcher32_scalar(data: &[u8]) -> u32 {
+    let words = data
+        .chunks(2)
+        .map(|word| u32::from(word[0]) | u32::from(word.get(1).copied().unwrap_or(0)) << 8);
+    let (a, b) = fletcher_scalar(0, 0, words, 65535);
+    b << 16 | a
+}
+
+/// Computes a reflected CRC one bit at a time.
+fn crc_scalar(reflected: u32, data: &[u8]) -> u32 {
+    let mut crc = !0;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                crc >> 1 ^ reflected
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+test_helpers::test_lanes! {
+    fn sums_match_scalar<const LANES: usize>() {
+        test_helpers::test_1(&|data: [u8; 100]| {
+            for len in 0..=data.len() {
+                let data = &data[..len];
+                proptest::prop_assert_eq!(checksum::adler32::<LANES>(data), adler32_scalar(data));
+                proptest::prop_assert_eq!(
+                    checksum::fletcher16::<LANES>(data),
+                    fletcher16_scalar(data)
+                );
+                proptest::prop_assert_eq!(
+                    checksum::fletcher32::<LANES>(data),
+                    fletcher32_scalar(data)
+                );
+            }
+            let (first, second) = data.split_at(usize::from(data[0]) % data.len());
+            let adler = checksum::adler32::<LANES>(first);
+            proptest::prop_assert_eq!(
+                checksum::adler32_update::<LANES>(adler, second),
+                adler32_scalar(&data)
+            );
+            Ok(())
+        });
+    }
+
+    fn long_runs<const LANES: usize>() {
+        // Large enough words and enough of them to overflow the sums if they weren't reduced
+        let data = vec![0xff; 3 * 5552 * 2 + 7];
+        assert_eq!(checksum::adler32::<LANES>(&data), adler32_scalar(&data));
+        assert_eq!(checksum::fletcher16::<LANES>(&data), fletcher16_scalar(&data));
+        assert_eq!(checksum::fletcher32::<LANES>(&data), fletcher32_scalar(&data));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn check_values() {
+    assert_eq!(checksum::adler32::<32>(b""), 1);
+    assert_eq!(checksum::adler32::<32>(b"Wikipedia"), 0x11e6_0398);
+    assert_eq!(checksum::fletcher16::<32>(b"abcdef"), 0x2057);
+    assert_eq!(checksum::fletcher32::<16>(b"abcdef"), 0x5650_2d2a);
+    assert_eq!(checksum::crc32(b""), 0);
+    assert_eq!(checksum::crc32(b"123456789"), 0xcbf4_3926);
+    assert_eq!(checksum::crc32c(b"123456789"), 0xe306_9283);
+    assert_eq!(checksum::crc32c(&[0; 32]), 0x8a91_36aa);
+    assert_eq!(checksum::crc32c(&[0xff; 32]), 0x62a8_ab43);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn crc_matches_scalar() {
+    test_helpers::test_1(&|data: [u8; 160]| {
+        for len in 0..=data.len() {
+            let data = &data[..len];
+            proptest::prop_assert_eq!(checksum::crc32(data), crc_scalar(0xedb8_8320, data));
+            proptest::prop_assert_eq!(checksum::crc32c(data), crc_scalar(0x82f6_3b78, data));
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn crc_update() {
+    test_helpers::test_2(&|data: [u8; 200], split: u8| {
+        let (first, second) = data.split_at(usize::from(split) % data.len());
+        proptest::prop_assert_eq!(
+            checksum::crc32_update(checksum::crc32(first), second),
+            checksum::crc32(&data)
+        );
+        proptest::prop_assert_eq!(
+            checksum::crc32c_update(checksum::crc32c(first), second),
+            checksum::crc32c(&data)
+        );
+        Ok(())
+    });
+}
@@ -0,0 +1,87 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{Mask, ToBitMask};
+use std_codec::hex;
+
+/// Decodes hex one digit at a time.
+fn decode_scalar(input: &[u8]) -> Result<Vec<u8>, std_codec::hex::DecodeError> {
+    use std_codec::hex::DecodeError;
+    if input.len() % 2 != 0 {
+        return Err(DecodeError::OddLength);
+    }
+    let digit = |index: usize| {
+        (input[index] as char)
+            .to_digit(16)
+            .map(|digit| digit as u8)
+            .ok_or(DecodeError::InvalidByte(index))
+    };
+    (0..input.len() / 2)
+        .map(|i| Ok(digit(2 * i)? << 4 | digit(2 * i + 1)?))
+        .collect()
+}
+
+test_helpers::test_lanes! {
+    fn roundtrip<const LANES: usize>()
+    where
+        Mask<i8, LANES>: ToBitMask,
+        <Mask<i8, LANES> as ToBitMask>::BitMask: Into<u64>,
+    {
+        test_helpers::test_1(&|input: [u8; 100]| {
+            for len in 0..=input.len() {
+                let input = &input[..len];
+                let expected: String = input.iter().map(|byte| format!("{:02x}", byte)).collect();
+                let mut encoded = [0; 200];
+                let encoded = hex::encode_to_slice::<LANES>(input, &mut encoded);
+                proptest::prop_assert_eq!(encoded, &expected);
+
+                let mut upper = [0; 200];
+                let upper = hex::encode_upper_to_slice::<LANES>(input, &mut upper);
+                proptest::prop_assert_eq!(upper, &expected.to_uppercase());
+
+                let mut decoded = [0; 100];
+                proptest::prop_assert_eq!(
+                    hex::decode_to_slice::<LANES>(upper.as_bytes(), &mut decoded),
+                    Ok(input)
+                );
+            }
+            Ok(())
+        });
+    }
+
+    fn decode_matches_scalar<const LANES: usize>()
+    where
+        Mask<i8, LANES>: ToBitMask,
+        <Mask<i8, LANES> as ToBitMask>::BitMask: Into<u64>,
+    {
+        test_helpers::test_2(&|input: [u8; 100], corrupt: [u8; 100]| {
+            // Mostly hex digits, with the occasional invalid byte
+            let digits = b"0123456789abcdefABCDEF";
+            let input: Vec<u8> = input
+                .iter()
+                .zip(corrupt)
+                .map(|(&byte, corrupt)| {
+                    if corrupt < 252 {
+                        digits[usize::from(byte) % digits.len()]
+                    } else {
+                        byte
+                    }
+                })
+                .collect();
+            for len in 0..=input.len() {
+                let input = &input[..len];
+                let mut decoded = [0; 50];
+                proptest::prop_assert_eq!(
+                    hex::decode_to_slice::<LANES>(input, &mut decoded).map(<[u8]>::to_vec),
+                    decode_scalar(input)
+                );
+            }
+            Ok(())
+        });
+    }
+}
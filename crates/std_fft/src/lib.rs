@@ -0,0 +1,27 @@
+#![no_std]
+#![feature(portable_simd)]
+//! Power-of-two fast Fourier transforms, built on portable SIMD.
+//!
+//! An [`FftPlan`] precomputes the twiddle factors for one length, and then transforms slices of
+//! interleaved [`Complex`] numbers in place. A [`RealFftPlan`] transforms real input through a
+//! complex transform of half the length, packing pairs of real numbers into complex ones.
+//!
+//! The transforms are decimation in time: the input is permuted into bit-reversed order, and
+//! then combined by radix-4 butterflies, which each do two radix-2 stages at once, with a final
+//! radix-2 stage when the length is an odd power of two. Every butterfly kernel loads `N`
+//! consecutive complex numbers into a [`SimdComplex`], so the transforms are generic over the
+//! lane count `N`; the first stages, whose butterflies span fewer than `N` numbers, are done one
+//! number at a time.
+//!
+//! The forward transform computes `X[k] = Σ x[j] e^(-2πijk/n)`, and the inverse transform
+//! computes `x[j] = (1/n) Σ X[k] e^(2πijk/n)`, so that it undoes the forward transform.
+extern crate alloc;
+
+use core_simd::simd;
+
+mod plan;
+mod real;
+
+pub use plan::FftPlan;
+pub use real::RealFftPlan;
+pub use std_float::{Complex, SimdComplex};
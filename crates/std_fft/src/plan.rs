@@ -0,0 +1,261 @@
+use crate::simd::{LaneCount, Simd, SupportedLaneCount};
+use alloc::vec::Vec;
+use std_float::{Complex, SimdComplex, StdFloat};
+
+/// Appends `e^(-2πik/denominator)` for `k` in `0..count` to `re` and `im`.
+///
+/// The factors are computed in `f64` and then rounded, so they are as accurate as possible
+/// in `f32` too.
+pub(crate) fn push_twiddles<T>(
+    re: &mut Vec<T>,
+    im: &mut Vec<T>,
+    count: usize,
+    denominator: usize,
+    round: fn(f64) -> T,
+) {
+    const LANES: usize = 8;
+    let step = Simd::<f64, LANES>::splat(-2. * core::f64::consts::PI / denominator as f64);
+    for start in (0..count).step_by(LANES) {
+        let k = Simd::from_array(core::array::from_fn(|i| (start + i) as f64));
+        let angles = k * step;
+        let (cos, sin) = (angles.cos(), angles.sin());
+        for i in 0..LANES.min(count - start) {
+            re.push(round(cos[i]));
+            im.push(round(sin[i]));
+        }
+    }
+}
+
+/// A plan for in-place complex FFTs of one power-of-two length.
+///
+/// The plan holds the twiddle factors of every stage, so it should be created once and reused
+/// for every transform of its length.
+///
+/// ```
+/// # use std_fft::{Complex, FftPlan};
+/// let plan = FftPlan::<f64>::new(4);
+/// let input = [1., 2., 3., 4.].map(|x| Complex::new(x, 0.));
+/// let mut data = input;
+/// plan.forward::<2>(&mut data);
+/// let expected = [(10., 0.), (-2., 2.), (-2., 0.), (-2., -2.)];
+/// for (x, (re, im)) in data.iter().zip(expected) {
+///     assert!((x.re - re).abs() < 1e-12 && (x.im - im).abs() < 1e-12);
+/// }
+/// plan.inverse::<2>(&mut data);
+/// for (x, y) in data.iter().zip(&input) {
+///     assert!((x.re - y.re).abs() < 1e-12 && (x.im - y.im).abs() < 1e-12);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FftPlan<T> {
+    len: usize,
+    /// The twiddle factors `e^(-πij/h)` for `j` in `0..h` of the stage combining transforms of
+    /// length `h` into transforms of length `2h`, for each `h` in order, starting at index `h - 1`
+    twiddle_re: Vec<T>,
+    twiddle_im: Vec<T>,
+}
+
+impl<T> FftPlan<T> {
+    /// Returns the length of the transforms.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `false`, since no plan has length 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Permutes `data` into bit-reversed order.
+    #[inline]
+    fn bit_reverse(&self, data: &mut [Complex<T>]) {
+        let bits = self.len.trailing_zeros();
+        if bits == 0 {
+            return;
+        }
+        for i in 0..self.len {
+            let j = i.reverse_bits() >> (usize::BITS - bits);
+            if i < j {
+                data.swap(i, j);
+            }
+        }
+    }
+}
+
+macro_rules! impl_plan {
+    { $($ty:ident)* } => {
+        $(
+        impl FftPlan<$ty> {
+            /// Creates a plan for transforms of length `len`.
+            ///
+            /// # Panics
+            /// Panics if `len` is not a power of two.
+            pub fn new(len: usize) -> Self {
+                assert!(len.is_power_of_two(), "the length must be a power of two");
+                let mut twiddle_re = Vec::with_capacity(len - 1);
+                let mut twiddle_im = Vec::with_capacity(len - 1);
+                let mut half = 1;
+                while half < len {
+                    push_twiddles(&mut twiddle_re, &mut twiddle_im, half, 2 * half, |x| x as $ty);
+                    half *= 2;
+                }
+                Self {
+                    len,
+                    twiddle_re,
+                    twiddle_im,
+                }
+            }
+
+            /// Loads `N` twiddle factors of the stage with transforms of length `half`,
+            /// starting at factor `j`.
+            #[inline]
+            fn twiddles<const N: usize>(&self, half: usize, j: usize) -> SimdComplex<$ty, N>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                let start = half - 1 + j;
+                SimdComplex::new(
+                    Simd::from_slice(&self.twiddle_re[start..]),
+                    Simd::from_slice(&self.twiddle_im[start..]),
+                )
+            }
+
+            /// Computes the FFT of `data` in place, `N` complex numbers at a time.
+            ///
+            /// The result is not normalized.
+            ///
+            /// # Panics
+            /// Panics if the length of `data` is not the length of the plan.
+            pub fn forward<const N: usize>(&self, data: &mut [Complex<$ty>])
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                assert_eq!(data.len(), self.len, "the data must have the length of the plan");
+                self.bit_reverse(data);
+
+                let mut quarter = 1;
+                while 4 * quarter <= self.len {
+                    if quarter >= N {
+                        self.radix4_stage::<N>(data, quarter);
+                    } else {
+                        self.radix4_stage::<1>(data, quarter);
+                    }
+                    quarter *= 4;
+                }
+                if 2 * quarter == self.len {
+                    if quarter >= N {
+                        self.radix2_stage::<N>(data, quarter);
+                    } else {
+                        self.radix2_stage::<1>(data, quarter);
+                    }
+                }
+            }
+
+            /// Computes the inverse FFT of `data` in place, `N` complex numbers at a time.
+            ///
+            /// The result is divided by the length, so that this undoes
+            /// [`forward`](Self::forward).
+            ///
+            /// # Panics
+            /// Panics if the length of `data` is not the length of the plan.
+            pub fn inverse<const N: usize>(&self, data: &mut [Complex<$ty>])
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                // The inverse is the conjugate of the forward transform of the conjugate
+                for x in data.iter_mut() {
+                    x.im = -x.im;
+                }
+                self.forward::<N>(data);
+                let scale = 1. / self.len as $ty;
+                for x in data.iter_mut() {
+                    *x = Complex::new(x.re * scale, -x.im * scale);
+                }
+            }
+
+            /// Combines pairs of transforms of length `half` into transforms of length
+            /// `2 * half`.
+            #[inline]
+            fn radix2_stage<const N: usize>(&self, data: &mut [Complex<$ty>], half: usize)
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                for block in data.chunks_exact_mut(2 * half) {
+                    for j in (0..half).step_by(N) {
+                        Self::radix2(&mut block[j..], half, self.twiddles::<N>(half, j));
+                    }
+                }
+            }
+
+            /// Combines groups of four transforms of length `quarter` into transforms of length
+            /// `4 * quarter`, doing two radix-2 stages at once.
+            #[inline]
+            fn radix4_stage<const N: usize>(&self, data: &mut [Complex<$ty>], quarter: usize)
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                for block in data.chunks_exact_mut(4 * quarter) {
+                    for j in (0..quarter).step_by(N) {
+                        let inner = self.twiddles::<N>(quarter, j);
+                        let outer = self.twiddles::<N>(2 * quarter, j);
+                        Self::radix4(&mut block[j..], quarter, inner, outer);
+                    }
+                }
+            }
+
+            /// Applies `N` radix-2 butterflies, to `data[i]` and `data[i + half]` for `i` in
+            /// `0..N`.
+            #[inline]
+            fn radix2<const N: usize>(
+                data: &mut [Complex<$ty>],
+                half: usize,
+                twiddle: SimdComplex<$ty, N>,
+            ) where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                let a = SimdComplex::from_slice(data);
+                let b = twiddle * SimdComplex::from_slice(&data[half..]);
+                (a + b).copy_to_slice(data);
+                (a - b).copy_to_slice(&mut data[half..]);
+            }
+
+            /// Applies `N` radix-4 butterflies, to `data[i + k * quarter]` for `k` in `0..4`
+            /// and `i` in `0..N`.
+            ///
+            /// `inner` holds the twiddle factors of the first of the two radix-2 stages, and
+            /// `outer` those of the second.
+            #[inline]
+            fn radix4<const N: usize>(
+                data: &mut [Complex<$ty>],
+                quarter: usize,
+                inner: SimdComplex<$ty, N>,
+                outer: SimdComplex<$ty, N>,
+            ) where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                let a0 = SimdComplex::from_slice(data);
+                let a1 = inner * SimdComplex::from_slice(&data[quarter..]);
+                let a2 = SimdComplex::from_slice(&data[2 * quarter..]);
+                let a3 = inner * SimdComplex::from_slice(&data[3 * quarter..]);
+                let (b0, b1) = (a0 + a1, a0 - a1);
+                let (b2, b3) = (a2 + a3, a2 - a3);
+
+                // The twiddle factors of the second half of the outer stage are those of the
+                // first half times `-i`
+                let c2 = outer * b2;
+                let c3 = outer * b3;
+                let c3 = SimdComplex::new(c3.im, -c3.re);
+
+                (b0 + c2).copy_to_slice(data);
+                (b1 + c3).copy_to_slice(&mut data[quarter..]);
+                (b0 - c2).copy_to_slice(&mut data[2 * quarter..]);
+                (b1 - c3).copy_to_slice(&mut data[3 * quarter..]);
+            }
+        }
+        )*
+    }
+}
+
+impl_plan! { f32 f64 }
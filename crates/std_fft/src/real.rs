@@ -0,0 +1,162 @@
+use crate::plan::{push_twiddles, FftPlan};
+use crate::simd::{LaneCount, SupportedLaneCount};
+use alloc::vec::Vec;
+use std_float::Complex;
+
+/// A plan for FFTs of real input of one power-of-two length `n`.
+///
+/// The spectrum of real input is conjugate-symmetric, so only its first `n / 2 + 1` values are
+/// computed. The input is packed into `n / 2` complex numbers, with the even elements in the
+/// real parts and the odd elements in the imaginary parts, which are transformed with a complex
+/// FFT of half the length and then separated into the spectrum.
+///
+/// ```
+/// # use std_fft::{Complex, RealFftPlan};
+/// let plan = RealFftPlan::<f32>::new(8);
+/// let mut spectrum = [Complex::default(); 5];
+/// plan.forward::<4>(&[1.; 8], &mut spectrum);
+/// assert_eq!(spectrum[0], Complex::new(8., 0.));
+/// assert!(spectrum[1..].iter().all(|x| x.re.abs() < 1e-6 && x.im.abs() < 1e-6));
+///
+/// let mut output = [0.; 8];
+/// plan.inverse::<4>(&mut spectrum, &mut output);
+/// assert!(output.iter().all(|x| (x - 1.).abs() < 1e-6));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RealFftPlan<T> {
+    complex: FftPlan<T>,
+    /// The twiddle factors `e^(-2πik/n)` for `k` in `0..=n / 4`
+    twiddle_re: Vec<T>,
+    twiddle_im: Vec<T>,
+}
+
+impl<T> RealFftPlan<T> {
+    /// Returns the length of the real input.
+    #[inline]
+    pub fn len(&self) -> usize {
+        2 * self.complex.len()
+    }
+
+    /// Returns `false`, since no plan has length 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+macro_rules! impl_real_plan {
+    { $($ty:ident)* } => {
+        $(
+        impl RealFftPlan<$ty> {
+            /// Creates a plan for transforms of real input of length `len`.
+            ///
+            /// # Panics
+            /// Panics if `len` is not a power of two, or is 1.
+            pub fn new(len: usize) -> Self {
+                assert!(
+                    len.is_power_of_two() && len > 1,
+                    "the length must be a power of two greater than 1"
+                );
+                let mut twiddle_re = Vec::with_capacity(len / 4 + 1);
+                let mut twiddle_im = Vec::with_capacity(len / 4 + 1);
+                push_twiddles(&mut twiddle_re, &mut twiddle_im, len / 4 + 1, len, |x| x as $ty);
+                Self {
+                    complex: FftPlan::<$ty>::new(len / 2),
+                    twiddle_re,
+                    twiddle_im,
+                }
+            }
+
+            /// Returns the twiddle factor `e^(-2πik/n)`.
+            #[inline]
+            fn twiddle(&self, k: usize) -> Complex<$ty> {
+                Complex::new(self.twiddle_re[k], self.twiddle_im[k])
+            }
+
+            /// Computes the first `n / 2 + 1` values of the FFT of `input` into `spectrum`,
+            /// `N` complex numbers at a time.
+            ///
+            /// The result is not normalized, and the imaginary parts of its first and last
+            /// values are zero.
+            ///
+            /// # Panics
+            /// Panics if `input` doesn't have the length of the plan, or `spectrum` doesn't have
+            /// half of it plus one.
+            pub fn forward<const N: usize>(&self, input: &[$ty], spectrum: &mut [Complex<$ty>])
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                let half = self.complex.len();
+                assert_eq!(input.len(), 2 * half, "the input must have the length of the plan");
+                assert_eq!(spectrum.len(), half + 1, "the spectrum must have half of it plus one");
+
+                for (z, x) in spectrum.iter_mut().zip(input.chunks_exact(2)) {
+                    *z = Complex::new(x[0], x[1]);
+                }
+                self.complex.forward::<N>(&mut spectrum[..half]);
+
+                // With `E` and `O` the spectra of the even and odd elements, the packed spectrum
+                // is `Z[k] = E[k] + i O[k]`, and the spectrum is `X[k] = E[k] + w^k O[k]`
+                for k in 0..=half / 2 {
+                    let z = spectrum[k];
+                    let mirror = spectrum[(half - k) % half];
+                    let even = Complex::new((z.re + mirror.re) / 2., (z.im - mirror.im) / 2.);
+                    let odd = Complex::new((z.im + mirror.im) / 2., (mirror.re - z.re) / 2.);
+                    let w = self.twiddle(k);
+                    let odd = Complex::new(
+                        w.re * odd.re - w.im * odd.im,
+                        w.re * odd.im + w.im * odd.re,
+                    );
+                    spectrum[k] = Complex::new(even.re + odd.re, even.im + odd.im);
+                    spectrum[half - k] = Complex::new(even.re - odd.re, odd.im - even.im);
+                }
+            }
+
+            /// Computes the real inverse FFT of the first `n / 2 + 1` values of a spectrum into
+            /// `output`, `N` complex numbers at a time, overwriting `spectrum`.
+            ///
+            /// The result is divided by the length, so that this undoes
+            /// [`forward`](Self::forward). The spectrum is assumed to be conjugate-symmetric,
+            /// so the imaginary parts of its first and last values are ignored.
+            ///
+            /// # Panics
+            /// Panics if `output` doesn't have the length of the plan, or `spectrum` doesn't have
+            /// half of it plus one.
+            pub fn inverse<const N: usize>(&self, spectrum: &mut [Complex<$ty>], output: &mut [$ty])
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                let half = self.complex.len();
+                assert_eq!(output.len(), 2 * half, "the output must have the length of the plan");
+                assert_eq!(spectrum.len(), half + 1, "the spectrum must have half of it plus one");
+                spectrum[0].im = 0.;
+                spectrum[half].im = 0.;
+
+                // Undo the separation in `forward`, halving so that the inverse of the half-length
+                // transform divides by the full length
+                for k in 0..=half / 2 {
+                    let x = spectrum[k];
+                    let mirror = spectrum[half - k];
+                    let even = Complex::new((x.re + mirror.re) / 2., (x.im - mirror.im) / 2.);
+                    let odd = Complex::new((x.re - mirror.re) / 2., (x.im + mirror.im) / 2.);
+                    let w = self.twiddle(k);
+                    let odd = Complex::new(
+                        w.re * odd.re + w.im * odd.im,
+                        w.re * odd.im - w.im * odd.re,
+                    );
+                    spectrum[k] = Complex::new(even.re - odd.im, even.im + odd.re);
+                    spectrum[half - k] = Complex::new(even.re + odd.im, odd.re - even.im);
+                }
+
+                self.complex.inverse::<N>(&mut spectrum[..half]);
+                for (x, z) in output.chunks_exact_mut(2).zip(&spectrum[..half]) {
+                    x[0] = z.re;
+                    x[1] = z.im;
+                }
+            }
+        }
+        )*
+    }
+}
+
+impl_real_plan! { f32 f64 }
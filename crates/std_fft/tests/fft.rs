@@ -0,0 +1,159 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Returns `len` pseudo-random numbers in `[-1, 1)`.
+fn noise(len: usize, seed: u64) -> Vec<f64> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 11) as f64 / (1u64 << 52) as f64 - 1.
+        })
+        .collect()
+}
+
+/// Computes the DFT of `input` directly from its definition, in `f64`.
+fn naive_dft(input: &[(f64, f64)], sign: f64) -> Vec<(f64, f64)> {
+    let n = input.len();
+    (0..n)
+        .map(|k| {
+            input
+                .iter()
+                .enumerate()
+                .fold((0., 0.), |(re, im), (j, &(x_re, x_im))| {
+                    // Reduce the exponent first, so that the angle is accurate for large `n`
+                    let angle = sign * 2. * core::f64::consts::PI * ((j * k) % n) as f64 / n as f64;
+                    let (sin, cos) = angle.sin_cos();
+                    (re + x_re * cos - x_im * sin, im + x_re * sin + x_im * cos)
+                })
+        })
+        .collect()
+}
+
+macro_rules! test_fft {
+    { $($ty:ident: $tolerance:expr),* } => {
+        $(
+            mod $ty {
+                #[cfg(target_arch = "wasm32")]
+                use wasm_bindgen_test::*;
+
+                use super::{naive_dft, noise};
+                use std_fft::{Complex, FftPlan, RealFftPlan};
+
+                const LENGTHS: [usize; 11] = [1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024];
+
+                /// Checks that `actual` matches `expected` to within the tolerance, relative to
+                /// the root-mean-square magnitude of `expected`.
+                fn assert_close(actual: &[Complex<$ty>], expected: &[(f64, f64)]) {
+                    assert_eq!(actual.len(), expected.len());
+                    let energy: f64 = expected.iter().map(|(re, im)| re * re + im * im).sum();
+                    let scale = (energy / expected.len() as f64).sqrt().max(1.);
+                    for (i, (x, y)) in actual.iter().zip(expected).enumerate() {
+                        let error = (f64::from(x.re) - y.0).hypot(f64::from(x.im) - y.1);
+                        assert!(
+                            error <= $tolerance * scale,
+                            "value {} of {}: {:?} is not close to {:?}",
+                            i,
+                            expected.len(),
+                            x,
+                            y
+                        );
+                    }
+                }
+
+                fn complex_input(len: usize) -> Vec<Complex<$ty>> {
+                    let re = noise(len, len as u64);
+                    let im = noise(len, !(len as u64));
+                    re.iter()
+                        .zip(&im)
+                        .map(|(&re, &im)| Complex::new(re as $ty, im as $ty))
+                        .collect()
+                }
+
+                fn widen(values: &[Complex<$ty>]) -> Vec<(f64, f64)> {
+                    values.iter().map(|x| (f64::from(x.re), f64::from(x.im))).collect()
+                }
+
+                test_helpers::test_lanes! {
+                    fn complex_matches_naive_dft<const LANES: usize>() {
+                        for len in LENGTHS {
+                            let plan = FftPlan::<$ty>::new(len);
+                            assert_eq!(plan.len(), len);
+                            let input = complex_input(len);
+
+                            let mut data = input.clone();
+                            plan.forward::<LANES>(&mut data);
+                            assert_close(&data, &naive_dft(&widen(&input), -1.));
+
+                            let mut data = input.clone();
+                            plan.inverse::<LANES>(&mut data);
+                            let expected: Vec<_> = naive_dft(&widen(&input), 1.)
+                                .iter()
+                                .map(|(re, im)| (re / len as f64, im / len as f64))
+                                .collect();
+                            assert_close(&data, &expected);
+
+                            plan.forward::<LANES>(&mut data);
+                            assert_close(&data, &widen(&input));
+                        }
+                    }
+
+                    fn real_matches_naive_dft<const LANES: usize>() {
+                        for len in &LENGTHS[1..] {
+                            let len = *len;
+                            let plan = RealFftPlan::<$ty>::new(len);
+                            assert_eq!(plan.len(), len);
+                            let input: Vec<$ty> =
+                                noise(len, len as u64).iter().map(|&x| x as $ty).collect();
+
+                            let mut spectrum = vec![Complex::default(); len / 2 + 1];
+                            plan.forward::<LANES>(&input, &mut spectrum);
+                            let widened: Vec<_> =
+                                input.iter().map(|&x| (f64::from(x), 0.)).collect();
+                            assert_close(&spectrum, &naive_dft(&widened, -1.)[..len / 2 + 1]);
+                            assert_eq!(spectrum[0].im, 0.);
+                            assert_eq!(spectrum[len / 2].im, 0.);
+
+                            let mut output = vec![0.; len];
+                            plan.inverse::<LANES>(&mut spectrum, &mut output);
+                            let output: Vec<_> =
+                                output.iter().map(|&x| Complex::new(x, 0.)).collect();
+                            assert_close(&output, &widened);
+                        }
+                    }
+                }
+
+                #[test]
+                #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                fn impulses() {
+                    // The transform of an impulse at `j` is `e^(-2πijk/n)`, with magnitude 1
+                    let plan = FftPlan::<$ty>::new(64);
+                    for j in [0, 1, 17, 63] {
+                        let mut data = vec![Complex::default(); 64];
+                        data[j] = Complex::new(1., 0.);
+                        plan.forward::<4>(&mut data);
+                        for x in &data {
+                            assert!((x.re.hypot(x.im) - 1.).abs() <= $tolerance);
+                        }
+                        assert_eq!(data[0], Complex::new(1., 0.));
+                    }
+                }
+
+                #[test]
+                #[should_panic(expected = "the length must be a power of two")]
+                fn rejects_other_lengths() {
+                    let _ = FftPlan::<$ty>::new(12);
+                }
+            }
+        )*
+    }
+}
+
+test_fft! { f32: 1e-5, f64: 1e-13 }
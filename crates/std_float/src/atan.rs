@@ -0,0 +1,105 @@
+//! Four-quadrant arctangents, after the Cephes `atan`, `atanf` and `atan2`.
+//!
+//! There is no intrinsic for these, so they are computed with vector arithmetic: the tangent is
+//! reduced to at most tan(π/8) in magnitude, and its arctangent approximated with a polynomial.
+
+use crate::simd::{LaneCount, Simd, SimdFloat, SimdPartialEq, SimdPartialOrd, SupportedLaneCount};
+
+/// Approximates the arctangent of each lane, which must be at most tan(π/8) in magnitude.
+#[inline]
+fn reduced_atan_f32<const N: usize>(x: Simd<f32, N>) -> Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let z = x * x;
+    let mut p = Simd::splat(0.080_537_446);
+    p = p * z - Simd::splat(0.138_776_85);
+    p = p * z + Simd::splat(0.199_777_11);
+    p = p * z - Simd::splat(0.333_329_5);
+    p * z * x + x
+}
+
+/// Approximates the arctangent of each lane, which must be at most 0.66 in magnitude.
+#[inline]
+fn reduced_atan_f64<const N: usize>(x: Simd<f64, N>) -> Simd<f64, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    const P: [f64; 5] = [
+        -0.875_060_860_003_190_4,
+        -16.157_537_187_333_652,
+        -75.008_557_923_147_05,
+        -122.886_668_449_013_61,
+        -64.850_219_049_420_25,
+    ];
+    const Q: [f64; 5] = [
+        24.858_464_901_423_062,
+        165.027_009_831_698_85,
+        432.881_060_491_290_27,
+        485.390_399_635_913_7,
+        194.550_657_148_261_4,
+    ];
+    let z = x * x;
+    let p = P
+        .iter()
+        .fold(Simd::splat(0.), |p, &c| p * z + Simd::splat(c));
+    let q = Q
+        .iter()
+        .fold(Simd::splat(1.), |q, &c| q * z + Simd::splat(c));
+    x * z * (p / q) + x
+}
+
+macro_rules! impl_atan2 {
+    {
+        $(
+            $atan2:ident: $ty:ident, $reduced_atan:ident,
+            $large:literal, $middle:literal, $more_bits:literal;
+        )*
+    } => {
+        $(
+        /// Returns the four-quadrant arctangent of `y / x` in each lane, in `[-π, π]`.
+        ///
+        /// Like the scalar `atan2`, this respects the signs of zeros and infinities.
+        #[inline]
+        pub(crate) fn $atan2<const N: usize>(y: Simd<$ty, N>, x: Simd<$ty, N>) -> Simd<$ty, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            use core::$ty::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+            let zero = Simd::splat(0.);
+            let one = Simd::splat(1.);
+
+            // Both zero or both infinite have the same angle as both one, up to signs, except
+            // that zeros are kept in `y` to give angles of 0 or π
+            let zeros = y.simd_eq(zero) & x.simd_eq(zero);
+            let infinities = y.is_infinite() & x.is_infinite();
+            let x = (zeros | infinities).select(one.copysign(x), x);
+            let y = infinities.select(one.copysign(y), y);
+
+            // Reduce with atan(t) = π/2 - atan(1/t) and atan(t) = π/4 + atan((t - 1)/(t + 1))
+            let tangent = y / x;
+            let t = tangent.abs();
+            let large = t.simd_gt(Simd::splat($large));
+            let middle = !large & t.simd_gt(Simd::splat($middle));
+            let reduced = large.select(-one / t, middle.select((t - one) / (t + one), t));
+            let offset = large.select(
+                Simd::splat(FRAC_PI_2),
+                middle.select(Simd::splat(FRAC_PI_4), zero),
+            );
+            // The rounding errors of the offsets
+            let more_bits = large.select(
+                Simd::splat($more_bits),
+                middle.select(Simd::splat($more_bits / 2.), zero),
+            );
+
+            let atan = (offset + ($reduced_atan(reduced) + more_bits)).copysign(tangent);
+            x.is_sign_negative().select(atan + Simd::splat(PI).copysign(y), atan)
+        }
+        )*
+    }
+}
+
+impl_atan2! {
+    atan2_f32: f32, reduced_atan_f32, 2.414_213_7, 0.414_213_57, 0.;
+    atan2_f64: f64, reduced_atan_f64, 2.414_213_562_373_095, 0.66, 6.123_233_995_736_766e-17;
+}
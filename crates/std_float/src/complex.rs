@@ -0,0 +1,242 @@
+use crate::simd::{
+    LaneCount, Simd, SimdElement, SimdFloat, SimdPartialEq, SimdPartialOrd, SupportedLaneCount,
+};
+use crate::StdFloat;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// A complex number, laid out as its real part followed by its imaginary part.
+///
+/// This is the layout of interleaved complex data, like C's `float _Complex`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Complex<T> {
+    /// The real part.
+    pub re: T,
+    /// The imaginary part.
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    /// Creates a complex number from its real and imaginary parts.
+    #[inline]
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+/// A vector of `N` complex numbers, with the real and imaginary parts in separate vectors.
+///
+/// Keeping the parts apart (a "structure of arrays") lets every operation work lanewise on
+/// whole vectors, with no shuffling within them. Interleaved complex data is converted with
+/// [`from_interleaved`](Self::from_interleaved) and [`to_interleaved`](Self::to_interleaved).
+///
+/// ```
+/// # use std_float::{Complex, SimdComplex};
+/// let a = SimdComplex::<f32, 2>::from_interleaved([Complex::new(1., 2.), Complex::new(0., 1.)]);
+/// let b = SimdComplex::splat(Complex::new(0., 1.));
+/// assert_eq!(
+///     (a * b).to_interleaved(),
+///     [Complex::new(-2., 1.), Complex::new(-1., 0.)]
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SimdComplex<T, const N: usize>
+where
+    T: SimdElement,
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// The real parts.
+    pub re: Simd<T, N>,
+    /// The imaginary parts.
+    pub im: Simd<T, N>,
+}
+
+impl<T, const N: usize> SimdComplex<T, N>
+where
+    T: SimdElement,
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Creates a vector of complex numbers from their real and imaginary parts.
+    #[inline]
+    pub const fn new(re: Simd<T, N>, im: Simd<T, N>) -> Self {
+        Self { re, im }
+    }
+
+    /// Creates a vector with every lane set to `value`.
+    #[inline]
+    pub fn splat(value: Complex<T>) -> Self {
+        Self::new(Simd::splat(value.re), Simd::splat(value.im))
+    }
+
+    /// Splits interleaved complex numbers into their real and imaginary parts.
+    #[inline]
+    pub fn from_interleaved(array: [Complex<T>; N]) -> Self {
+        // Safety: `Complex<T>` is `repr(C)` with two `T` fields and no padding, so an array of
+        // `N` of them has the size and alignment of `2 * N` `T`s
+        let [first, second] = unsafe { array.as_ptr().cast::<[[T; N]; 2]>().read() };
+        let (re, im) = Simd::from_array(first).deinterleave(Simd::from_array(second));
+        Self::new(re, im)
+    }
+
+    /// Interleaves the real and imaginary parts into complex numbers.
+    #[inline]
+    pub fn to_interleaved(self) -> [Complex<T>; N] {
+        let (first, second) = self.re.interleave(self.im);
+        let parts = [first.to_array(), second.to_array()];
+        // Safety: as in `from_interleaved`, the layouts are the same
+        unsafe { parts.as_ptr().cast::<[Complex<T>; N]>().read() }
+    }
+
+    /// Loads interleaved complex numbers from the start of `slice`.
+    ///
+    /// # Panics
+    /// Panics if `slice` is shorter than `N`.
+    #[inline]
+    pub fn from_slice(slice: &[Complex<T>]) -> Self {
+        Self::from_interleaved(slice[..N].try_into().unwrap())
+    }
+
+    /// Stores the complex numbers, interleaved, to the start of `slice`.
+    ///
+    /// # Panics
+    /// Panics if `slice` is shorter than `N`.
+    #[inline]
+    pub fn copy_to_slice(self, slice: &mut [Complex<T>]) {
+        slice[..N].copy_from_slice(&self.to_interleaved());
+    }
+
+    /// Returns the complex number in lane `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is not less than `N`.
+    #[inline]
+    pub fn extract(self, index: usize) -> Complex<T> {
+        Complex::new(self.re[index], self.im[index])
+    }
+}
+
+macro_rules! impl_complex {
+    { $($ty:ident)* } => {
+        $(
+        impl<const N: usize> SimdComplex<$ty, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            /// Returns the complex conjugate of each lane.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn conj(self) -> Self {
+                Self::new(self.re, -self.im)
+            }
+
+            /// Returns the squared magnitude of each lane.
+            ///
+            /// This is cheaper than [`abs`](Self::abs), but overflows for magnitudes above the
+            /// square root of the largest float.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn norm_sqr(self) -> Simd<$ty, N> {
+                self.re * self.re + self.im * self.im
+            }
+
+            /// Returns the magnitude of each lane.
+            ///
+            /// Like the scalar `hypot`, this doesn't overflow or underflow unless the result
+            /// does, and is infinite if either part is infinite.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn abs(self) -> Simd<$ty, N> {
+                let (re, im) = (self.re.abs(), self.im.abs());
+                // Comparisons with NaN are false, so a NaN part is always `large` or `small`
+                let re_larger = re.simd_ge(im);
+                let large = re_larger.select(re, im);
+                let small = re_larger.select(im, re);
+                let ratio = small / large;
+                let magnitude = large * (Simd::splat(1.) + ratio * ratio).sqrt();
+                let infinite = re.is_infinite() | im.is_infinite();
+                let zero = re.simd_eq(Simd::splat(0.)) & im.simd_eq(Simd::splat(0.));
+                infinite.select(
+                    Simd::splat(<$ty>::INFINITY),
+                    zero.select(Simd::splat(0.), magnitude),
+                )
+            }
+
+            /// Returns the argument of each lane, in `[-π, π]`.
+            ///
+            /// This is `im.atan2(re)`, so it follows the signs of zeros: the argument of
+            /// `-1 - 0i` is `-π`.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn arg(self) -> Simd<$ty, N> {
+                self.im.atan2(self.re)
+            }
+
+            /// Returns `e` raised to the power of each lane.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn exp(self) -> Self {
+                let magnitude = self.re.exp();
+                Self::new(magnitude * self.im.cos(), magnitude * self.im.sin())
+            }
+        }
+
+        impl<const N: usize> Add for SimdComplex<$ty, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self::new(self.re + rhs.re, self.im + rhs.im)
+            }
+        }
+
+        impl<const N: usize> Sub for SimdComplex<$ty, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self::new(self.re - rhs.re, self.im - rhs.im)
+            }
+        }
+
+        impl<const N: usize> Mul for SimdComplex<$ty, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self::new(
+                    self.re * rhs.re - self.im * rhs.im,
+                    self.re * rhs.im + self.im * rhs.re,
+                )
+            }
+        }
+
+        impl<const N: usize> Div for SimdComplex<$ty, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            /// Divides with the textbook formula, multiplying by the conjugate of `rhs` and
+            /// dividing by its squared magnitude, which can overflow or underflow for very
+            /// large or small `rhs`.
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                let numerator = self * rhs.conj();
+                let denominator = rhs.norm_sqr();
+                Self::new(numerator.re / denominator, numerator.im / denominator)
+            }
+        }
+        )*
+    }
+}
+
+impl_complex! { f32 f64 }
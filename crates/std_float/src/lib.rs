@@ -22,6 +22,11 @@ use experimental as sealed;
 
 use crate::sealed::Sealed;
 
+mod atan;
+mod complex;
+
+pub use complex::{Complex, SimdComplex};
+
 // "platform intrinsics" are essentially "codegen intrinsics"
 // each of these may be scalarized and lowered to a libm call
 extern "platform-intrinsic" {
@@ -42,6 +47,15 @@ extern "platform-intrinsic" {
 
     // fma
     fn simd_fma<T>(x: T, y: T, z: T) -> T;
+
+    // exp
+    fn simd_fexp<T>(x: T) -> T;
+
+    // sin
+    fn simd_fsin<T>(x: T) -> T;
+
+    // cos
+    fn simd_fcos<T>(x: T) -> T;
 }
 
 /// This trait provides a possibly-temporary implementation of float functions
@@ -116,6 +130,34 @@ pub trait StdFloat: Sealed + Sized {
     /// Returns the floating point's fractional value, with its integer part removed.
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn fract(self) -> Self;
+
+    /// Returns `e` raised to the power of each lane.
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp(self) -> Self {
+        unsafe { simd_fexp(self) }
+    }
+
+    /// Returns the sine of each lane, in radians.
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sin(self) -> Self {
+        unsafe { simd_fsin(self) }
+    }
+
+    /// Returns the cosine of each lane, in radians.
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn cos(self) -> Self {
+        unsafe { simd_fcos(self) }
+    }
+
+    /// Returns the four-quadrant arctangent of `self / other` in each lane, in radians.
+    ///
+    /// The result is in `[-π, π]`, following the signs of zeros and infinities like the
+    /// scalar `atan2`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn atan2(self, other: Self) -> Self;
 }
 
 impl<const N: usize> Sealed for Simd<f32, N> where LaneCount<N>: SupportedLaneCount {}
@@ -132,6 +174,12 @@ where
     fn fract(self) -> Self {
         self - self.trunc()
     }
+
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        atan::atan2_f32(self, other)
+    }
 }
 
 impl<const N: usize> StdFloat for Simd<f64, N>
@@ -144,6 +192,12 @@ where
     fn fract(self) -> Self {
         self - self.trunc()
     }
+
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        atan::atan2_f64(self, other)
+    }
 }
 
 #[cfg(test)]
@@ -161,6 +215,10 @@ mod tests {
         let _xt = x.trunc();
         let _xfma = x.mul_add(x, x);
         let _xsqrt = x.sqrt();
+        let _xexp = x.exp();
+        let _xsin = x.sin();
+        let _xcos = x.cos();
+        let _xatan2 = x.atan2(x2);
         let _ = x2.abs() * x2;
     }
-}
\ No newline at end of file
+}
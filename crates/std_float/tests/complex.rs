@@ -0,0 +1,175 @@
+#![feature(portable_simd)]
+// The tests are written against `core_simd`, which the library only uses with `as_crate`.
+#![cfg(feature = "as_crate")]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_complex {
+    { $($ty:ident),* } => {
+        $(
+            mod $ty {
+                #[cfg(target_arch = "wasm32")]
+                use wasm_bindgen_test::*;
+
+                use core_simd::simd::Simd;
+                use std_float::{Complex, SimdComplex, StdFloat};
+
+                /// Checks that `actual` is within `ulps` units in the last place of `expected`,
+                /// or that both are the same infinity or NaN.
+                fn close(actual: $ty, expected: $ty, ulps: $ty) -> bool {
+                    if actual.is_nan() || expected.is_nan() || expected.is_infinite() {
+                        return actual.to_bits() == expected.to_bits()
+                            || (actual.is_nan() && expected.is_nan());
+                    }
+                    let scale = expected.abs().max(<$ty>::MIN_POSITIVE);
+                    (actual - expected).abs() <= ulps * <$ty>::EPSILON * scale
+                }
+
+                test_helpers::test_lanes! {
+                    fn arithmetic_matches_scalar<const LANES: usize>() {
+                        test_helpers::test_3(&|a: [$ty; LANES], b: [$ty; LANES], c: [$ty; LANES]| {
+                            let z = SimdComplex::new(Simd::from_array(a), Simd::from_array(b));
+                            let w = SimdComplex::new(Simd::from_array(c), Simd::from_array(a));
+                            let (sum, difference) = (z + w, z - w);
+                            let (product, quotient) = (z * w, z / w);
+                            let (conj, norm_sqr) = (z.conj(), z.norm_sqr());
+                            for i in 0..LANES {
+                                let (zr, zi, wr, wi) = (a[i], b[i], c[i], a[i]);
+                                let denominator = wr * wr + wi * wi;
+                                let expected = [
+                                    (sum, zr + wr, zi + wi),
+                                    (difference, zr - wr, zi - wi),
+                                    (product, zr * wr - zi * wi, zr * wi + zi * wr),
+                                    (
+                                        quotient,
+                                        (zr * wr + zi * wi) / denominator,
+                                        (zi * wr - zr * wi) / denominator,
+                                    ),
+                                    (conj, zr, -zi),
+                                ];
+                                for (actual, re, im) in expected {
+                                    test_helpers::prop_assert_biteq!(actual.re[i], re);
+                                    test_helpers::prop_assert_biteq!(actual.im[i], im);
+                                }
+                                test_helpers::prop_assert_biteq!(norm_sqr[i], zr * zr + zi * zi);
+                            }
+                            Ok(())
+                        });
+                    }
+
+                    fn functions_match_scalar<const LANES: usize>() {
+                        test_helpers::test_2(&|a: [$ty; LANES], b: [$ty; LANES]| {
+                            let z = SimdComplex::new(Simd::from_array(a), Simd::from_array(b));
+                            let (abs, arg, exp) = (z.abs(), z.arg(), z.exp());
+                            let atan2 = Simd::from_array(b).atan2(Simd::from_array(a));
+                            for i in 0..LANES {
+                                proptest::prop_assert!(
+                                    close(abs[i], a[i].hypot(b[i]), 2.),
+                                    "abs({}, {}) = {}", a[i], b[i], abs[i]
+                                );
+                                proptest::prop_assert!(
+                                    close(arg[i], b[i].atan2(a[i]), 2.),
+                                    "arg({}, {}) = {}", a[i], b[i], arg[i]
+                                );
+                                test_helpers::prop_assert_biteq!(arg[i], atan2[i]);
+                                if a[i].is_finite() && b[i].is_finite() && b[i].abs() < 1e3 {
+                                    let magnitude = a[i].exp();
+                                    let (re, im) = (magnitude * b[i].cos(), magnitude * b[i].sin());
+                                    proptest::prop_assert!(close(exp.re[i], re, 2.));
+                                    proptest::prop_assert!(close(exp.im[i], im, 2.));
+                                }
+                            }
+                            Ok(())
+                        });
+                    }
+
+                    fn interleaved_round_trip<const LANES: usize>() {
+                        test_helpers::test_2(&|a: [$ty; LANES], b: [$ty; LANES]| {
+                            let interleaved: [Complex<$ty>; LANES] =
+                                core::array::from_fn(|i| Complex::new(a[i], b[i]));
+                            let z = SimdComplex::from_interleaved(interleaved);
+                            for i in 0..LANES {
+                                test_helpers::prop_assert_biteq!(z.re[i], a[i]);
+                                test_helpers::prop_assert_biteq!(z.im[i], b[i]);
+                                let lane = z.extract(i);
+                                test_helpers::prop_assert_biteq!(lane.re, a[i]);
+                                test_helpers::prop_assert_biteq!(lane.im, b[i]);
+                            }
+
+                            let mut slice = vec![Complex::default(); LANES + 1];
+                            z.copy_to_slice(&mut slice[1..]);
+                            let round_trip = SimdComplex::<$ty, LANES>::from_slice(&slice[1..]);
+                            for (actual, expected) in z.to_interleaved().iter().zip(&interleaved) {
+                                test_helpers::prop_assert_biteq!(actual.re, expected.re);
+                                test_helpers::prop_assert_biteq!(actual.im, expected.im);
+                            }
+                            let round_trip = round_trip.to_interleaved();
+                            for (actual, expected) in round_trip.iter().zip(&interleaved) {
+                                test_helpers::prop_assert_biteq!(actual.re, expected.re);
+                                test_helpers::prop_assert_biteq!(actual.im, expected.im);
+                            }
+                            Ok(())
+                        });
+                    }
+                }
+
+                #[test]
+                #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                fn atan2_is_accurate() {
+                    // Sweep every range of the argument reduction, in every quadrant
+                    let steps: Vec<$ty> = (-400..=400).map(|i| i as $ty / 97.).collect();
+                    for &y in &steps {
+                        for x in steps.chunks_exact(4) {
+                            let x = Simd::from_array([x[0], x[1], x[2], x[3]]);
+                            let actual = Simd::splat(y).atan2(x);
+                            for i in 0..4 {
+                                let expected = y.atan2(x[i]);
+                                assert!(
+                                    close(actual[i], expected, 2.),
+                                    "atan2({}, {}) = {}, expected {}",
+                                    y,
+                                    x[i],
+                                    actual[i],
+                                    expected
+                                );
+                            }
+                        }
+                    }
+                }
+
+                #[test]
+                #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+                fn edge_cases() {
+                    use core::$ty::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+                    let (inf, nan) = (<$ty>::INFINITY, <$ty>::NAN);
+                    let z = SimdComplex::<$ty, 8>::from_interleaved([
+                        Complex::new(0., 0.),
+                        Complex::new(-0., 0.),
+                        Complex::new(-1., -0.),
+                        Complex::new(0., -2.),
+                        Complex::new(-inf, inf),
+                        Complex::new(inf, nan),
+                        Complex::new(3e30, 4e30),
+                        Complex::new(3e-30, -4e-30),
+                    ]);
+                    let arg = z.arg().to_array();
+                    assert_eq!(arg[..4], [0., PI, -PI, -FRAC_PI_2]);
+                    assert!(close(arg[4], 3. * FRAC_PI_4, 1.));
+                    assert!(arg[5].is_nan());
+                    assert_eq!(arg[0].to_bits(), (0.0 as $ty).to_bits());
+
+                    let abs = z.abs().to_array();
+                    assert_eq!(abs[..6], [0., 0., 1., 2., inf, inf]);
+                    assert!(close(abs[6], 5e30, 2.));
+                    assert!(close(abs[7], 5e-30, 2.));
+                }
+            }
+        )*
+    }
+}
+
+test_complex! { f32, f64 }
@@ -0,0 +1,145 @@
+use crate::simd::{
+    LaneCount, Simd, SimdInt, SimdPartialEq, SimdPartialOrd, SupportedLaneCount, ToBitMask,
+};
+
+/// The control byte of a bucket that has never been full.
+pub const EMPTY: u8 = 0b1111_1111;
+
+/// The control byte of a bucket whose entry has been removed.
+pub const DELETED: u8 = 0b1000_0000;
+
+/// Returns the position part of each lane of `hashes`, from which probing starts.
+///
+/// A table with a power-of-two number of buckets starts probing for a hash at
+/// `h1 & (buckets - 1)`.
+#[inline]
+pub fn h1<const N: usize>(hashes: Simd<u64, N>) -> Simd<usize, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    hashes.cast()
+}
+
+/// Returns the tag part of each lane of `hashes`: its top 7 bits, which are stored in the control
+/// byte of a full bucket.
+///
+/// Tags never have the high bit set, so they can't be confused with [`EMPTY`] or [`DELETED`].
+#[inline]
+pub fn h2<const N: usize>(hashes: Simd<u64, N>) -> Simd<u8, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    (hashes >> Simd::splat(57)).cast()
+}
+
+/// A group of 16 control bytes of a SwissTable-style hash table, probed all at once.
+///
+/// Each control byte is [`EMPTY`], [`DELETED`], or the [tag](h2) of the entry in its bucket.
+/// Lookups compare the tag of the key against every byte of a group, and check the buckets
+/// that match, until they reach a group with an empty bucket.
+///
+/// ```
+/// # use std_hash::{Group, EMPTY};
+/// let mut control = [EMPTY; 16];
+/// control[3] = 0x2a;
+/// control[9] = 0x2a;
+/// control[12] = 0x11;
+/// let group = Group::load(&control);
+/// assert_eq!(group.match_tag(0x2a).collect::<Vec<_>>(), [3, 9]);
+/// assert_eq!(group.match_empty().lowest_set_bit(), Some(0));
+/// assert_eq!(group.match_full().count(), 3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Group(Simd<u8, 16>);
+
+impl Group {
+    /// The number of control bytes in a group.
+    pub const WIDTH: usize = 16;
+
+    /// Creates a group from its control bytes.
+    #[inline]
+    pub fn from_array(control: [u8; 16]) -> Self {
+        Self(Simd::from_array(control))
+    }
+
+    /// Loads a group from the first 16 bytes of `control`.
+    ///
+    /// # Panics
+    /// Panics if `control` is shorter than 16 bytes.
+    #[inline]
+    pub fn load(control: &[u8]) -> Self {
+        Self(Simd::from_slice(control))
+    }
+
+    /// Returns the control bytes of the group.
+    #[inline]
+    pub fn to_array(self) -> [u8; 16] {
+        self.0.to_array()
+    }
+
+    /// Returns the buckets whose control byte is `tag`.
+    ///
+    /// Matches may be false positives, so the keys of the buckets still need to be compared.
+    #[inline]
+    pub fn match_tag(self, tag: u8) -> BitMask {
+        BitMask(self.0.simd_eq(Simd::splat(tag)).to_bitmask())
+    }
+
+    /// Returns the buckets that are [`EMPTY`].
+    #[inline]
+    pub fn match_empty(self) -> BitMask {
+        self.match_tag(EMPTY)
+    }
+
+    /// Returns the buckets that are [`EMPTY`] or [`DELETED`], which are the ones that can be
+    /// inserted into.
+    #[inline]
+    pub fn match_empty_or_deleted(self) -> BitMask {
+        BitMask(self.0.cast::<i8>().is_negative().to_bitmask())
+    }
+
+    /// Returns the buckets that hold an entry.
+    #[inline]
+    pub fn match_full(self) -> BitMask {
+        BitMask(self.0.simd_lt(Simd::splat(DELETED)).to_bitmask())
+    }
+}
+
+/// The set of buckets of a [`Group`] that matched a probe, with bit `i` set if bucket `i` did.
+///
+/// Iterating over it yields the indices of the matching buckets, lowest first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BitMask(pub u16);
+
+impl BitMask {
+    /// Returns `true` if any bucket matched.
+    #[inline]
+    pub fn any_bit_set(self) -> bool {
+        self.0 != 0
+    }
+
+    /// Returns the index of the first bucket that matched, if any.
+    #[inline]
+    pub fn lowest_set_bit(self) -> Option<usize> {
+        (self.0 != 0).then(|| self.0.trailing_zeros() as usize)
+    }
+}
+
+impl Iterator for BitMask {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        let index = self.lowest_set_bit()?;
+        self.0 &= self.0 - 1;
+        Some(index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for BitMask {}
@@ -0,0 +1,22 @@
+#![no_std]
+#![feature(portable_simd)]
+//! Non-cryptographic hashing primitives for hash tables, built on portable SIMD.
+//!
+//! The mixers hash every lane of a vector at once, so that a batch of `N` keys can be hashed
+//! together before probing a table:
+//!
+//! * [`murmur3_fmix32`] and [`murmur3_fmix64`] are the MurmurHash3 finalizers, and
+//!   [`murmur3_32`] hashes 32-bit keys exactly as MurmurHash3 does.
+//! * [`wyhash_mix`] folds a 128-bit product, and [`hash_u64`] uses it to hash 64-bit keys.
+//! * [`xxh3_accumulate`] is the inner step of XXH3 over long inputs.
+//!
+//! A hash is split into a probe position with [`h1`] and a 7-bit tag with [`h2`]. A [`Group`]
+//! matches a tag against 16 control bytes of a SwissTable-style table at once, and returns the
+//! matching buckets as a [`BitMask`] that iterates over their indices.
+use core_simd::simd;
+
+mod group;
+mod mix;
+
+pub use group::*;
+pub use mix::*;
@@ -0,0 +1,170 @@
+use crate::simd::{LaneCount, Simd, SupportedLaneCount, Swizzle};
+
+/// The secret constants of wyhash, used to key [`hash_u64`].
+const WYHASH_SECRET: [u64; 2] = [0xa076_1d64_78bd_642f, 0xe703_7ed1_a0b4_28db];
+
+#[inline]
+fn rotate_left_u32<const N: usize>(x: Simd<u32, N>, k: u32) -> Simd<u32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    x << Simd::splat(k) | x >> Simd::splat(32 - k)
+}
+
+/// Returns the low and high halves of the full 128-bit products of `a` and `b`.
+#[inline]
+fn mul_wide<const N: usize>(a: Simd<u64, N>, b: Simd<u64, N>) -> (Simd<u64, N>, Simd<u64, N>)
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let low_bits = Simd::splat(u64::from(u32::MAX));
+    let half = Simd::splat(32);
+    let (a0, a1) = (a & low_bits, a >> half);
+    let (b0, b1) = (b & low_bits, b >> half);
+
+    // None of these can overflow, since each is at most three 32-bit numbers or a product
+    let low_product = a0 * b0;
+    let cross_0 = a0 * b1;
+    let cross_1 = a1 * b0;
+    let middle = (low_product >> half) + (cross_0 & low_bits) + (cross_1 & low_bits);
+    let low = low_product & low_bits | middle << half;
+    let high = a1 * b1 + (cross_0 >> half) + (cross_1 >> half) + (middle >> half);
+    (low, high)
+}
+
+/// Applies the 32-bit MurmurHash3 finalizer to each lane.
+///
+/// The finalizer is a bijection that makes every bit of the result depend on every bit of the
+/// input.
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # use core_simd::simd::Simd;
+/// # use std_hash::murmur3_fmix32;
+/// let x = Simd::from_array([0, 1, 2, 3]);
+/// assert_eq!(murmur3_fmix32(x)[..2], [0, 0x514e_28b7]);
+/// ```
+#[inline]
+pub fn murmur3_fmix32<const N: usize>(x: Simd<u32, N>) -> Simd<u32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let mut h = x;
+    h ^= h >> Simd::splat(16);
+    h *= Simd::splat(0x85eb_ca6b);
+    h ^= h >> Simd::splat(13);
+    h *= Simd::splat(0xc2b2_ae35);
+    h ^ h >> Simd::splat(16)
+}
+
+/// Applies the 64-bit MurmurHash3 finalizer to each lane.
+///
+/// The finalizer is a bijection that makes every bit of the result depend on every bit of the
+/// input.
+#[inline]
+pub fn murmur3_fmix64<const N: usize>(x: Simd<u64, N>) -> Simd<u64, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let mut h = x;
+    h ^= h >> Simd::splat(33);
+    h *= Simd::splat(0xff51_afd7_ed55_8ccd);
+    h ^= h >> Simd::splat(33);
+    h *= Simd::splat(0xc4ce_b9fe_1a85_ec53);
+    h ^ h >> Simd::splat(33)
+}
+
+/// Hashes the 4-byte little-endian encoding of each lane of `keys` with 32-bit MurmurHash3.
+///
+/// Each lane of the result is `murmur3_x86_32(&key.to_le_bytes(), seed)`.
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # use core_simd::simd::Simd;
+/// # use std_hash::murmur3_32;
+/// let keys = Simd::from_array([0x8765_4321, 0]);
+/// assert_eq!(murmur3_32(keys, 0).to_array(), [0xf55b_516b, 0x2362_f9de]);
+/// ```
+#[inline]
+pub fn murmur3_32<const N: usize>(keys: Simd<u32, N>, seed: u32) -> Simd<u32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let mut k = keys * Simd::splat(0xcc9e_2d51);
+    k = rotate_left_u32(k, 15);
+    k *= Simd::splat(0x1b87_3593);
+
+    let mut h = Simd::splat(seed) ^ k;
+    h = rotate_left_u32(h, 13);
+    h = h * Simd::splat(5) + Simd::splat(0xe654_6b64);
+    // The length of the key, in bytes
+    murmur3_fmix32(h ^ Simd::splat(4))
+}
+
+/// Multiplies each lane of `a` and `b` to 128 bits, and folds the product to 64 bits by
+/// XORing its halves, as wyhash does.
+///
+/// Unlike a plain multiplication, the result depends on the high bits of both operands.
+#[inline]
+pub fn wyhash_mix<const N: usize>(a: Simd<u64, N>, b: Simd<u64, N>) -> Simd<u64, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let (low, high) = mul_wide(a, b);
+    low ^ high
+}
+
+/// Hashes each lane of `keys` with a single [`wyhash_mix`] keyed by `seed`.
+///
+/// Each lane of the result is `wyhash_mix(key ^ seed ^ s0, s1)`, where `s0` and `s1` are the
+/// default wyhash secrets. This is a good hash for 64-bit keys in a hash table, but not a
+/// bijection, and not resistant to collisions chosen by an attacker who knows the seed.
+#[inline]
+pub fn hash_u64<const N: usize>(keys: Simd<u64, N>, seed: u64) -> Simd<u64, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    wyhash_mix(
+        keys ^ Simd::splat(seed ^ WYHASH_SECRET[0]),
+        Simd::splat(WYHASH_SECRET[1]),
+    )
+}
+
+const fn swap_pairs_index<const N: usize>() -> [usize; N] {
+    assert!(N % 2 == 0, "the number of lanes must be even");
+    let mut index = [0; N];
+    let mut i = 0;
+    while i < N {
+        index[i] = i ^ 1;
+        i += 1;
+    }
+    index
+}
+
+/// Swaps each even lane with the odd lane after it.
+struct SwapPairs;
+
+impl<const N: usize> Swizzle<N, N> for SwapPairs {
+    const INDEX: [usize; N] = swap_pairs_index::<N>();
+}
+
+/// Accumulates a stripe of input into the accumulators of XXH3, as its `accumulate_512` step
+/// does for 8 lanes.
+///
+/// Lane `i` of the result is lane `i` of `acc`, plus lane `i ^ 1` of `data`, plus the product
+/// of the two halves of lane `i` of `data ^ secret`.
+///
+/// `N` must be even, and is checked when the function is instantiated.
+#[inline]
+pub fn xxh3_accumulate<const N: usize>(
+    acc: Simd<u64, N>,
+    data: Simd<u64, N>,
+    secret: Simd<u64, N>,
+) -> Simd<u64, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let keyed = data ^ secret;
+    let product = (keyed & Simd::splat(u64::from(u32::MAX))) * (keyed >> Simd::splat(32));
+    acc + SwapPairs::swizzle(data) + product
+}
@@ -0,0 +1,221 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{LaneCount, Simd, SupportedLaneCount};
+use std_hash::*;
+
+fn fmix32_scalar(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ h >> 16
+}
+
+fn fmix64_scalar(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    h ^ h >> 33
+}
+
+/// MurmurHash3_x86_32 of a 4-byte key.
+fn murmur3_32_scalar(key: u32, seed: u32) -> u32 {
+    let k = key
+        .wrapping_mul(0xcc9e_2d51)
+        .rotate_left(15)
+        .wrapping_mul(0x1b87_3593);
+    let h = (seed ^ k)
+        .rotate_left(13)
+        .wrapping_mul(5)
+        .wrapping_add(0xe654_6b64);
+    fmix32_scalar(h ^ 4)
+}
+
+fn wyhash_mix_scalar(a: u64, b: u64) -> u64 {
+    let product = u128::from(a) * u128::from(b);
+    product as u64 ^ (product >> 64) as u64
+}
+
+/// The `accumulate_512` step of XXH3, for any even number of lanes.
+fn xxh3_accumulate_scalar(acc: &mut [u64], data: &[u64], secret: &[u64]) {
+    for i in 0..acc.len() {
+        let keyed = data[i] ^ secret[i];
+        acc[i ^ 1] = acc[i ^ 1].wrapping_add(data[i]);
+        acc[i] = acc[i].wrapping_add((keyed & 0xffff_ffff) * (keyed >> 32));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn murmur3_known_answers() {
+    let keys = Simd::from_array([0x8765_4321, 0x8765_4321, 0, u32::MAX]);
+    let seeds = [0, 0x5082_edee, 0, 0];
+    let expected = [0xf55b_516b, 0x2362_f9de, 0x2362_f9de, 0x7629_3b50];
+    for lane in 0..4 {
+        assert_eq!(murmur3_32(keys, seeds[lane])[lane], expected[lane]);
+    }
+}
+
+test_helpers::test_lanes! {
+    fn mixers_match_scalar<const LANES: usize>() {
+        test_helpers::test_3(&|a: [u64; LANES], b: [u64; LANES], seed: u64| {
+            let (x, y) = (Simd::from_array(a), Simd::from_array(b));
+            let narrow = x.cast::<u32>();
+            let fmix32 = murmur3_fmix32(narrow);
+            let murmur = murmur3_32(narrow, seed as u32);
+            let fmix64 = murmur3_fmix64(x);
+            let mix = wyhash_mix(x, y);
+            let hashes = hash_u64(x, seed);
+            for lane in 0..LANES {
+                proptest::prop_assert_eq!(fmix32[lane], fmix32_scalar(a[lane] as u32));
+                proptest::prop_assert_eq!(
+                    murmur[lane],
+                    murmur3_32_scalar(a[lane] as u32, seed as u32)
+                );
+                proptest::prop_assert_eq!(fmix64[lane], fmix64_scalar(a[lane]));
+                proptest::prop_assert_eq!(mix[lane], wyhash_mix_scalar(a[lane], b[lane]));
+                proptest::prop_assert_eq!(
+                    hashes[lane],
+                    wyhash_mix_scalar(a[lane] ^ seed ^ 0xa076_1d64_78bd_642f, 0xe703_7ed1_a0b4_28db)
+                );
+            }
+            Ok(())
+        });
+    }
+
+    fn split_hashes<const LANES: usize>() {
+        test_helpers::test_1(&|a: [u64; LANES]| {
+            let hashes = Simd::from_array(a);
+            let (positions, tags) = (h1(hashes), h2(hashes));
+            for lane in 0..LANES {
+                proptest::prop_assert_eq!(positions[lane], a[lane] as usize);
+                proptest::prop_assert_eq!(tags[lane], (a[lane] >> 57) as u8);
+                proptest::prop_assert!(tags[lane] < DELETED);
+            }
+            Ok(())
+        });
+    }
+}
+
+fn xxh3_matches_scalar<const LANES: usize>()
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    test_helpers::test_3(
+        &|acc: [u64; LANES], data: [u64; LANES], secret: [u64; LANES]| {
+            let actual = xxh3_accumulate(
+                Simd::from_array(acc),
+                Simd::from_array(data),
+                Simd::from_array(secret),
+            );
+            let mut expected = acc;
+            xxh3_accumulate_scalar(&mut expected, &data, &secret);
+            proptest::prop_assert_eq!(actual.to_array(), expected);
+            Ok(())
+        },
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn xxh3_accumulate_even_lanes() {
+    xxh3_matches_scalar::<2>();
+    xxh3_matches_scalar::<4>();
+    xxh3_matches_scalar::<8>();
+    xxh3_matches_scalar::<16>();
+    xxh3_matches_scalar::<32>();
+    xxh3_matches_scalar::<64>();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn group_matches_scalar() {
+    test_helpers::test_2(&|control: [u8; 16], tag: u8| {
+        let group = Group::from_array(control);
+        let matching =
+            |f: &dyn Fn(u8) -> bool| -> Vec<usize> { (0..16).filter(|&i| f(control[i])).collect() };
+
+        proptest::prop_assert_eq!(
+            group.match_tag(tag).collect::<Vec<_>>(),
+            matching(&|byte| byte == tag)
+        );
+        proptest::prop_assert_eq!(
+            group.match_empty().collect::<Vec<_>>(),
+            matching(&|byte| byte == EMPTY)
+        );
+        proptest::prop_assert_eq!(
+            group.match_empty_or_deleted().collect::<Vec<_>>(),
+            matching(&|byte| byte & 0x80 != 0)
+        );
+        let full = group.match_full();
+        proptest::prop_assert_eq!(full.len(), matching(&|byte| byte & 0x80 == 0).len());
+        proptest::prop_assert_eq!(full.collect::<Vec<_>>(), matching(&|byte| byte & 0x80 == 0));
+        proptest::prop_assert_eq!(
+            group.match_tag(tag).lowest_set_bit(),
+            control.iter().position(|&byte| byte == tag)
+        );
+        proptest::prop_assert_eq!(Group::load(&control).to_array(), control);
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn probe_table() {
+    // Insert keys into a small table with triangular probing over groups, then look them up
+    const GROUPS: usize = 4;
+    let mut control = [EMPTY; GROUPS * Group::WIDTH];
+    let mut slots = [0u64; GROUPS * Group::WIDTH];
+    let keys = Simd::<u64, 8>::from_array(core::array::from_fn(|i| i as u64 * 1000 + 7));
+    let hashes = hash_u64(keys, 42);
+    let (positions, tags) = (h1(hashes), h2(hashes));
+
+    let probe = |lane: usize, f: &mut dyn FnMut(usize) -> bool| {
+        let mut group = positions[lane] % GROUPS;
+        for stride in 1..=GROUPS {
+            if f(group * Group::WIDTH) {
+                return;
+            }
+            group = (group + stride) % GROUPS;
+        }
+        panic!("the table is full");
+    };
+
+    for lane in 0..8 {
+        probe(lane, &mut |start| {
+            let group = Group::load(&control[start..]);
+            match group.match_empty_or_deleted().lowest_set_bit() {
+                Some(index) => {
+                    control[start + index] = tags[lane];
+                    slots[start + index] = keys[lane];
+                    true
+                }
+                None => false,
+            }
+        });
+    }
+
+    for lane in 0..8 {
+        let mut found = false;
+        probe(lane, &mut |start| {
+            let group = Group::load(&control[start..]);
+            found = group
+                .match_tag(tags[lane])
+                .any(|index| slots[start + index] == keys[lane]);
+            found || group.match_empty().any_bit_set()
+        });
+        assert!(found);
+    }
+    let full: usize = control
+        .chunks(Group::WIDTH)
+        .map(|group| Group::load(group).match_full().len())
+        .sum();
+    assert_eq!(full, 8);
+}
@@ -0,0 +1,86 @@
+#![no_std]
+#![feature(portable_simd)]
+//! Random number generators that produce vectors, built on portable SIMD.
+//!
+//! Each generator runs `N` independent streams, one per lane, and produces a whole
+//! [`Simd`](simd::Simd) of random numbers at a time. The lanes of a generator start at different
+//! points of the same underlying sequence, or in different streams of it, so that they don't
+//! overlap:
+//!
+//! * [`Xoshiro256PlusPlus`] spaces its lanes 2<sup>128</sup> outputs apart with the xoshiro
+//!   jump function.
+//! * [`Pcg32`] gives each lane its own stream, and can advance each lane by a different number
+//!   of steps in logarithmic time.
+//! * [`Philox4x32`] is counter-based: each output is a function of the key, the lane and a
+//!   counter, so any lane can be moved to any position in constant time.
+//!
+//! Every generator is seedable, and produces the same numbers on every target.
+use core_simd::simd;
+
+mod pcg;
+mod philox;
+mod xoshiro;
+
+pub use pcg::Pcg32;
+pub use philox::Philox4x32;
+pub use xoshiro::Xoshiro256PlusPlus;
+
+use simd::{LaneCount, Simd, SimdUint, SupportedLaneCount};
+
+/// A random number generator producing `N` random numbers at a time.
+pub trait SimdRng<const N: usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Returns a vector of random `u32`s.
+    fn next_u32(&mut self) -> Simd<u32, N>;
+
+    /// Returns a vector of random `u64`s.
+    fn next_u64(&mut self) -> Simd<u64, N>;
+
+    /// Returns a vector of `f32`s, uniformly distributed in `[0, 1)`.
+    ///
+    /// Each lane is a multiple of 2<sup>-24</sup>, made from the upper 24 bits of
+    /// [`next_u32`](Self::next_u32).
+    #[inline]
+    fn next_f32(&mut self) -> Simd<f32, N> {
+        (self.next_u32() >> Simd::splat(8)).cast::<f32>() * Simd::splat(1. / (1u32 << 24) as f32)
+    }
+
+    /// Returns a vector of `f64`s, uniformly distributed in `[0, 1)`.
+    ///
+    /// Each lane is a multiple of 2<sup>-53</sup>, made from the upper 53 bits of
+    /// [`next_u64`](Self::next_u64).
+    #[inline]
+    fn next_f64(&mut self) -> Simd<f64, N> {
+        (self.next_u64() >> Simd::splat(11)).cast::<f64>() * Simd::splat(1. / (1u64 << 53) as f64)
+    }
+}
+
+/// Combines two vectors of `u32`s into one of `u64`s, with `low` in the low halves.
+#[inline]
+fn combine<const N: usize>(low: Simd<u32, N>, high: Simd<u32, N>) -> Simd<u64, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    high.cast::<u64>() << Simd::splat(32) | low.cast::<u64>()
+}
+
+/// Returns the next output of SplitMix64, which expands a seed into generator state.
+#[inline]
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Returns `true` if any lane of `x` is nonzero.
+#[inline]
+fn any_nonzero<const N: usize>(x: Simd<u64, N>) -> bool
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    x.reduce_or() != 0
+}
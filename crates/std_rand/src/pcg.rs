@@ -0,0 +1,100 @@
+use crate::simd::{LaneCount, Mask, Simd, SimdPartialEq, SupportedLaneCount};
+use crate::{any_nonzero, combine, SimdRng};
+
+const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+/// The PCG32 (XSH RR 64/32) generator, running in each lane.
+///
+/// Lane `i` produces the same sequence as the reference `pcg32_srandom_r(seed, stream + i)`, so
+/// every lane has its own stream. Each lane can be [advanced](Self::advance) by a different
+/// number of steps, in time logarithmic in the largest step.
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # use std_rand::{Pcg32, SimdRng};
+/// let mut rng = Pcg32::<4>::new(42, 54);
+/// assert_eq!(rng.next_u32()[0], 0xa15c_02b7);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Pcg32<const N: usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    state: Simd<u64, N>,
+    increment: Simd<u64, N>,
+}
+
+impl<const N: usize> Pcg32<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Creates a generator from a seed, with lane `i` in stream `stream + i`.
+    ///
+    /// Streams are 63-bit, and wrap around.
+    #[inline]
+    pub fn new(seed: u64, stream: u64) -> Self {
+        let streams = Simd::splat(stream) + Simd::from_array(core::array::from_fn(|i| i as u64));
+        let mut rng = Self {
+            state: Simd::splat(0),
+            increment: streams << Simd::splat(1) | Simd::splat(1),
+        };
+        rng.step();
+        rng.state += Simd::splat(seed);
+        rng.step();
+        rng
+    }
+
+    #[inline]
+    fn step(&mut self) {
+        self.state = self.state * Simd::splat(MULTIPLIER) + self.increment;
+    }
+
+    /// Advances each lane by the number of steps in the same lane of `delta`, as if that many
+    /// numbers had been generated in it.
+    ///
+    /// This composes the linear congruential steps by repeated squaring, so it takes time
+    /// logarithmic in the largest step.
+    #[inline]
+    pub fn advance(&mut self, delta: Simd<u64, N>) {
+        // The step `x * multiplier + increment` applied `2^i` times
+        let mut multiplier = Simd::splat(MULTIPLIER);
+        let mut increment = self.increment;
+        // The steps applied so far
+        let mut total_multiplier = Simd::splat(1);
+        let mut total_increment = Simd::splat(0);
+
+        let mut delta = delta;
+        while any_nonzero(delta) {
+            let apply: Mask<i64, N> = (delta & Simd::splat(1)).simd_eq(Simd::splat(1));
+            total_multiplier = apply.select(total_multiplier * multiplier, total_multiplier);
+            total_increment =
+                apply.select(total_increment * multiplier + increment, total_increment);
+            increment = (multiplier + Simd::splat(1)) * increment;
+            multiplier *= multiplier;
+            delta >>= Simd::splat(1);
+        }
+        self.state = total_multiplier * self.state + total_increment;
+    }
+}
+
+impl<const N: usize> SimdRng<N> for Pcg32<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn next_u32(&mut self) -> Simd<u32, N> {
+        let state = self.state;
+        self.step();
+        let xorshifted = ((state >> Simd::splat(18) ^ state) >> Simd::splat(27)).cast::<u32>();
+        let rotation = (state >> Simd::splat(59)).cast::<u32>();
+        xorshifted >> rotation | xorshifted << ((Simd::splat(32) - rotation) & Simd::splat(31))
+    }
+
+    /// Combines two consecutive outputs, with the first in the low halves.
+    #[inline]
+    fn next_u64(&mut self) -> Simd<u64, N> {
+        let low = self.next_u32();
+        let high = self.next_u32();
+        combine(low, high)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::simd::{LaneCount, Simd, SupportedLaneCount};
+use crate::{combine, SimdRng};
+
+const MULTIPLIERS: [u32; 2] = [0xd251_1f53, 0xcd9e_8d57];
+const WEYL: [u32; 2] = [0x9e37_79b9, 0xbb67_ae85];
+const ROUNDS: usize = 10;
+
+/// Returns the high and low halves of the lanewise products of `x` and `multiplier`.
+#[inline]
+fn mul_hi_lo<const N: usize>(x: Simd<u32, N>, multiplier: u32) -> (Simd<u32, N>, Simd<u32, N>)
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let product = x.cast::<u64>() * Simd::splat(u64::from(multiplier));
+    ((product >> Simd::splat(32)).cast(), product.cast())
+}
+
+/// The Philox4x32-10 counter-based generator, running in each lane.
+///
+/// Every block of four outputs is [a function](Self::block) of the key and a 128-bit counter.
+/// In lane `i`, the counter of block `n` is `[n as u32, (n >> 32) as u32, i, stream]`, so every
+/// lane and stream has its own sequence of 2<sup>64</sup> blocks, and each lane can be moved to
+/// any block in constant time.
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # use std_rand::{Philox4x32, SimdRng};
+/// let mut rng = Philox4x32::<4>::new(1, 0);
+/// let first = rng.next_u32();
+/// rng.set_counter(Default::default());
+/// assert_eq!(rng.next_u32(), first);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Philox4x32<const N: usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    key: [u32; 2],
+    stream: u32,
+    counter: Simd<u64, N>,
+    buffer: [Simd<u32, N>; 4],
+    used: usize,
+}
+
+impl<const N: usize> Philox4x32<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Creates a generator keyed by `seed`, with every lane at the first block of `stream`.
+    #[inline]
+    pub fn new(seed: u64, stream: u32) -> Self {
+        Self {
+            key: [seed as u32, (seed >> 32) as u32],
+            stream,
+            counter: Simd::splat(0),
+            buffer: [Simd::splat(0); 4],
+            used: 4,
+        }
+    }
+
+    /// Computes the Philox4x32-10 block for each lane of `counter`, under `key`.
+    ///
+    /// Word `j` of `counter` holds word `j` of each lane's counter, and likewise for the result.
+    #[inline]
+    pub fn block(key: [u32; 2], counter: [Simd<u32, N>; 4]) -> [Simd<u32, N>; 4] {
+        let [mut k0, mut k1] = key;
+        let [mut c0, mut c1, mut c2, mut c3] = counter;
+        for _ in 0..ROUNDS {
+            let (hi0, lo0) = mul_hi_lo(c0, MULTIPLIERS[0]);
+            let (hi1, lo1) = mul_hi_lo(c2, MULTIPLIERS[1]);
+            [c0, c1, c2, c3] = [
+                hi1 ^ c1 ^ Simd::splat(k0),
+                lo1,
+                hi0 ^ c3 ^ Simd::splat(k1),
+                lo0,
+            ];
+            k0 = k0.wrapping_add(WEYL[0]);
+            k1 = k1.wrapping_add(WEYL[1]);
+        }
+        [c0, c1, c2, c3]
+    }
+
+    /// Returns the index of the block each lane will generate next.
+    ///
+    /// Outputs left over from the current block are discarded when the counter is changed.
+    #[inline]
+    pub fn counter(&self) -> Simd<u64, N> {
+        self.counter
+    }
+
+    /// Moves each lane to the start of the block in the same lane of `counter`.
+    #[inline]
+    pub fn set_counter(&mut self, counter: Simd<u64, N>) {
+        self.counter = counter;
+        self.used = 4;
+    }
+
+    /// Moves each lane forward by the number of blocks in the same lane of `blocks`, discarding
+    /// any outputs left over from the current block.
+    #[inline]
+    pub fn advance(&mut self, blocks: Simd<u64, N>) {
+        self.set_counter(self.counter + blocks);
+    }
+}
+
+impl<const N: usize> SimdRng<N> for Philox4x32<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn next_u32(&mut self) -> Simd<u32, N> {
+        if self.used == 4 {
+            let lanes = Simd::from_array(core::array::from_fn(|i| i as u32));
+            let counter = [
+                self.counter.cast(),
+                (self.counter >> Simd::splat(32)).cast(),
+                lanes,
+                Simd::splat(self.stream),
+            ];
+            self.buffer = Self::block(self.key, counter);
+            self.counter += Simd::splat(1);
+            self.used = 0;
+        }
+        self.used += 1;
+        self.buffer[self.used - 1]
+    }
+
+    /// Combines two consecutive outputs, with the first in the low halves.
+    #[inline]
+    fn next_u64(&mut self) -> Simd<u64, N> {
+        let low = self.next_u32();
+        let high = self.next_u32();
+        combine(low, high)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::simd::{LaneCount, Simd, SupportedLaneCount};
+use crate::{splitmix64, SimdRng};
+
+/// Advances a state by 2<sup>128</sup> outputs.
+const JUMP: [u64; 4] = [
+    0x180e_c6d3_3cfd_0aba,
+    0xd5a6_1266_f0c9_392c,
+    0xa958_2618_e03f_c9aa,
+    0x39ab_dc45_29b1_661c,
+];
+
+/// Advances a state by 2<sup>192</sup> outputs.
+const LONG_JUMP: [u64; 4] = [
+    0x76e1_5d3e_fefd_cbbf,
+    0xc500_4e44_1c52_2fb3,
+    0x7771_0069_854e_e241,
+    0x3910_9bb0_2acb_e635,
+];
+
+#[inline]
+fn rotate_left<const N: usize>(x: Simd<u64, N>, k: u64) -> Simd<u64, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    x << Simd::splat(k) | x >> Simd::splat(64 - k)
+}
+
+/// The xoshiro256++ generator, running in each lane.
+///
+/// Lane `i` produces the same sequence as a scalar xoshiro256++ generator that has been
+/// [jumped](Self::jump) `i` times, so the lanes are 2<sup>128</sup> outputs apart and never
+/// overlap in practice.
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # use std_rand::{SimdRng, Xoshiro256PlusPlus};
+/// let mut rng = Xoshiro256PlusPlus::<4>::seed_from_u64(7);
+/// let x = rng.next_f64();
+/// assert!(x.to_array().iter().all(|x| (0. ..1.).contains(x)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Xoshiro256PlusPlus<const N: usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    state: [Simd<u64, N>; 4],
+}
+
+impl<const N: usize> Xoshiro256PlusPlus<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Creates a generator whose first lane starts from `state`, and whose other lanes are
+    /// jumped from it.
+    ///
+    /// # Panics
+    /// Panics if `state` is all zeros, which the generator never leaves.
+    #[inline]
+    pub fn from_state(state: [u64; 4]) -> Self {
+        assert!(state != [0; 4], "the state must not be all zeros");
+        let mut lane = Xoshiro256PlusPlus::<1> {
+            state: state.map(Simd::<u64, 1>::splat),
+        };
+        let mut lanes = [[0; 4]; N];
+        for lanes in lanes.iter_mut() {
+            *lanes = lane.state.map(|word| word[0]);
+            lane.jump_by(JUMP);
+        }
+        Self {
+            state: core::array::from_fn(|word| Simd::from_array(lanes.map(|lane| lane[word]))),
+        }
+    }
+
+    /// Creates a generator from a seed, which is expanded into a state with SplitMix64.
+    #[inline]
+    pub fn seed_from_u64(seed: u64) -> Self {
+        let mut seed = seed;
+        Self::from_state([(); 4].map(|_| splitmix64(&mut seed)))
+    }
+
+    /// Applies a jump polynomial to the state of each lane.
+    #[inline]
+    fn jump_by(&mut self, jump: [u64; 4]) {
+        let mut state = [Simd::splat(0); 4];
+        for word in jump {
+            for bit in 0..64 {
+                if word >> bit & 1 == 1 {
+                    for (state, word) in state.iter_mut().zip(self.state) {
+                        *state ^= word;
+                    }
+                }
+                self.next_u64();
+            }
+        }
+        self.state = state;
+    }
+
+    /// Advances each lane by `N` jumps of 2<sup>128</sup> outputs, past the start of every other
+    /// lane's sequence.
+    ///
+    /// Jumping a copy of a generator gives a generator whose lanes don't overlap the original's,
+    /// for use in another thread.
+    #[inline]
+    pub fn jump(&mut self) {
+        for _ in 0..N {
+            self.jump_by(JUMP);
+        }
+    }
+
+    /// Advances each lane by 2<sup>192</sup> outputs.
+    ///
+    /// This gives up to 2<sup>64</sup> starting points for generators that can each be
+    /// [jumped](Self::jump) many times.
+    #[inline]
+    pub fn long_jump(&mut self) {
+        self.jump_by(LONG_JUMP);
+    }
+}
+
+impl<const N: usize> SimdRng<N> for Xoshiro256PlusPlus<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Returns the upper halves of [`next_u64`](Self::next_u64), which are its strongest bits.
+    #[inline]
+    fn next_u32(&mut self) -> Simd<u32, N> {
+        (self.next_u64() >> Simd::splat(32)).cast()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> Simd<u64, N> {
+        let [mut s0, mut s1, mut s2, mut s3] = self.state;
+        let result = rotate_left(s0 + s3, 23) + s0;
+        let t = s1 << Simd::splat(17);
+        s2 ^= s0;
+        s3 ^= s1;
+        s1 ^= s2;
+        s0 ^= s3;
+        s2 ^= t;
+        s3 = rotate_left(s3, 45);
+        self.state = [s0, s1, s2, s3];
+        result
+    }
+}
@@ -0,0 +1,217 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::Simd;
+use std_rand::{Pcg32, Philox4x32, SimdRng, Xoshiro256PlusPlus};
+
+/// The reference xoshiro256++ step.
+fn xoshiro_scalar(s: &mut [u64; 4]) -> u64 {
+    let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+    let t = s[1] << 17;
+    s[2] ^= s[0];
+    s[3] ^= s[1];
+    s[1] ^= s[2];
+    s[0] ^= s[3];
+    s[2] ^= t;
+    s[3] = s[3].rotate_left(45);
+    result
+}
+
+/// The reference xoshiro256++ jump, from the generator's authors.
+fn xoshiro_jump_scalar(s: &mut [u64; 4]) {
+    const JUMP: [u64; 4] = [
+        0x180ec6d33cfd0aba,
+        0xd5a61266f0c9392c,
+        0xa9582618e03fc9aa,
+        0x39abdc4529b1661c,
+    ];
+    let mut jumped = [0; 4];
+    for word in JUMP {
+        for bit in 0..64 {
+            if word & 1 << bit != 0 {
+                for (jumped, s) in jumped.iter_mut().zip(*s) {
+                    *jumped ^= s;
+                }
+            }
+            xoshiro_scalar(s);
+        }
+    }
+    *s = jumped;
+}
+
+/// The reference PCG32 generator.
+struct PcgScalar {
+    state: u64,
+    increment: u64,
+}
+
+impl PcgScalar {
+    fn new(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: stream << 1 | 1,
+        };
+        rng.next();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next();
+        rng
+    }
+
+    fn next(&mut self) -> u32 {
+        let old = self.state;
+        self.state = old
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(self.increment);
+        let xorshifted = ((old >> 18 ^ old) >> 27) as u32;
+        xorshifted.rotate_right((old >> 59) as u32)
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn known_answers() {
+    let mut xoshiro = Xoshiro256PlusPlus::<1>::from_state([1, 2, 3, 4]);
+    let outputs: Vec<u64> = (0..4).map(|_| xoshiro.next_u64()[0]).collect();
+    assert_eq!(
+        outputs,
+        [41943041, 58720359, 3588806011781223, 3591011842654386]
+    );
+
+    let mut pcg = Pcg32::<1>::new(42, 54);
+    let outputs: Vec<u32> = (0..6).map(|_| pcg.next_u32()[0]).collect();
+    assert_eq!(
+        outputs,
+        [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e]
+    );
+
+    // From the Random123 known-answer tests
+    let block = |key: [u32; 2], counter: [u32; 4]| {
+        Philox4x32::<1>::block(key, counter.map(Simd::splat)).map(|word| word[0])
+    };
+    assert_eq!(
+        block([0, 0], [0; 4]),
+        [0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8]
+    );
+    assert_eq!(
+        block([u32::MAX; 2], [u32::MAX; 4]),
+        [0x408f276d, 0x41c83b0e, 0xa20bc7c6, 0x6d5451fd]
+    );
+    assert_eq!(
+        block(
+            [0xa4093822, 0x299f31d0],
+            [0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344]
+        ),
+        [0xd16cfe09, 0x94fdcceb, 0x5001e420, 0x24126ea1]
+    );
+}
+
+test_helpers::test_lanes! {
+    fn xoshiro_lanes_are_jumped<const LANES: usize>() {
+        let mut state = [0x1234, 0x5678, 0x9abc, 0xdef0];
+        let mut rng = Xoshiro256PlusPlus::<LANES>::from_state(state);
+        let mut lanes = Vec::new();
+        for _ in 0..LANES {
+            lanes.push(state);
+            xoshiro_jump_scalar(&mut state);
+        }
+        for _ in 0..20 {
+            let outputs = rng.next_u64();
+            for (lane, state) in lanes.iter_mut().enumerate() {
+                assert_eq!(outputs[lane], xoshiro_scalar(state));
+            }
+        }
+
+        // Jumping moves every lane past the start of the last one
+        let mut jumped = Xoshiro256PlusPlus::<LANES>::from_state(state);
+        rng = Xoshiro256PlusPlus::<LANES>::from_state([0x1234, 0x5678, 0x9abc, 0xdef0]);
+        rng.jump();
+        assert_eq!(rng.next_u64(), jumped.next_u64());
+    }
+
+    fn pcg_lanes_are_streams<const LANES: usize>() {
+        let mut rng = Pcg32::<LANES>::new(0xcafe, u64::MAX - 3);
+        let mut lanes: Vec<_> = (0..LANES as u64)
+            .map(|lane| PcgScalar::new(0xcafe, (u64::MAX - 3).wrapping_add(lane)))
+            .collect();
+        for _ in 0..20 {
+            let outputs = rng.next_u32();
+            for (lane, scalar) in lanes.iter_mut().enumerate() {
+                assert_eq!(outputs[lane], scalar.next());
+            }
+        }
+    }
+
+    fn pcg_advance_matches_stepping<const LANES: usize>() {
+        let deltas = Simd::from_array(core::array::from_fn(|lane| (lane * lane * 7) as u64));
+        let mut rng = Pcg32::<LANES>::new(3, 9);
+        let mut lanes: Vec<_> = (0..LANES as u64).map(|lane| PcgScalar::new(3, 9 + lane)).collect();
+        rng.advance(deltas);
+        for (scalar, &delta) in lanes.iter_mut().zip(deltas.as_array()) {
+            for _ in 0..delta {
+                scalar.next();
+            }
+        }
+        let outputs = rng.next_u32();
+        for (lane, scalar) in lanes.iter_mut().enumerate() {
+            assert_eq!(outputs[lane], scalar.next());
+        }
+
+        // Advancing by the full period is a no-op, and advances compose
+        let mut a = Pcg32::<LANES>::new(3, 9);
+        let mut b = a.clone();
+        a.advance(Simd::splat(u64::MAX));
+        a.advance(Simd::splat(1));
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+
+    fn philox_lanes_match_blocks<const LANES: usize>() {
+        let seed = 0x0123_4567_89ab_cdef;
+        let mut rng = Philox4x32::<LANES>::new(seed, 77);
+        rng.advance(Simd::splat(u64::from(u32::MAX)));
+        for block in u64::from(u32::MAX)..u64::from(u32::MAX) + 3 {
+            let words: Vec<_> = (0..4).map(|_| rng.next_u32()).collect();
+            for lane in 0..LANES {
+                let counter = [block as u32, (block >> 32) as u32, lane as u32, 77];
+                let expected = Philox4x32::<1>::block(
+                    [seed as u32, (seed >> 32) as u32],
+                    counter.map(Simd::<u32, 1>::splat),
+                );
+                for (word, expected) in words.iter().zip(expected) {
+                    assert_eq!(word[lane], expected[0]);
+                }
+            }
+        }
+        assert_eq!(rng.counter(), Simd::splat(u64::from(u32::MAX) + 3));
+    }
+
+    fn floats_are_uniform<const LANES: usize>() {
+        fn check<R: SimdRng<LANES>, const LANES: usize>(mut rng: R)
+        where
+            core_simd::simd::LaneCount<LANES>: core_simd::simd::SupportedLaneCount,
+        {
+            let draws = 4096 / LANES;
+            let (mut sum32, mut sum64) = (0., 0.);
+            for _ in 0..draws {
+                for &x in rng.next_f32().as_array() {
+                    assert!((0. ..1.).contains(&x));
+                    sum32 += f64::from(x);
+                }
+                for &x in rng.next_f64().as_array() {
+                    assert!((0. ..1.).contains(&x));
+                    sum64 += x;
+                }
+            }
+            let count = (draws * LANES) as f64;
+            assert!((sum32 / count - 0.5).abs() < 0.03);
+            assert!((sum64 / count - 0.5).abs() < 0.03);
+        }
+        check(Xoshiro256PlusPlus::<LANES>::seed_from_u64(1));
+        check(Pcg32::<LANES>::new(1, 1));
+        check(Philox4x32::<LANES>::new(1, 1));
+    }
+}
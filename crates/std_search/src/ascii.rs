@@ -0,0 +1,133 @@
+use crate::simd::{LaneCount, Mask, Simd, SimdPartialEq, SimdPartialOrd, SupportedLaneCount};
+
+/// Returns a mask of the lanes that are in `lo..=hi`.
+#[inline]
+fn in_range<const N: usize>(bytes: Simd<u8, N>, lo: u8, hi: u8) -> Mask<i8, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    bytes.simd_ge(Simd::splat(lo)) & bytes.simd_le(Simd::splat(hi))
+}
+
+/// Flips the case of the lanes that are in `lo..=hi`.
+#[inline]
+fn flip_case<const N: usize>(bytes: Simd<u8, N>, lo: u8, hi: u8) -> Simd<u8, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    bytes ^ in_range(bytes, lo, hi).select(Simd::splat(0x20), Simd::splat(0))
+}
+
+/// Applies `f` to each block of `N` bytes, and `scalar` to the remainder.
+#[inline]
+fn map_blocks<const N: usize>(
+    bytes: &mut [u8],
+    f: impl Fn(Simd<u8, N>) -> Simd<u8, N>,
+    scalar: impl Fn(&mut [u8]),
+) where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let mut chunks = bytes.chunks_exact_mut(N);
+    for chunk in &mut chunks {
+        chunk.copy_from_slice(f(Simd::from_slice(chunk)).as_array());
+    }
+    scalar(chunks.into_remainder());
+}
+
+/// Returns `true` if every byte in `bytes` is ASCII, checking `N` bytes at a time.
+///
+/// ```
+/// # use std_search::is_ascii;
+/// assert!(is_ascii::<16>(b"plain old ASCII text"));
+/// assert!(!is_ascii::<16>("not ASCII: ß".as_bytes()));
+/// ```
+#[inline]
+#[must_use]
+pub fn is_ascii<const N: usize>(bytes: &[u8]) -> bool
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let mut chunks = bytes.chunks_exact(N);
+    let non_ascii = chunks.by_ref().any(|chunk| {
+        Simd::<u8, N>::from_slice(chunk)
+            .simd_ge(Simd::splat(0x80))
+            .any()
+    });
+    !non_ascii && chunks.remainder().is_ascii()
+}
+
+/// Converts the ASCII letters in `bytes` to upper case in place, `N` bytes at a time.
+///
+/// Other bytes are unchanged, like `<[u8]>::make_ascii_uppercase`.
+///
+/// ```
+/// # use std_search::make_ascii_uppercase;
+/// let mut bytes = *b"Hello, World! 123";
+/// make_ascii_uppercase::<8>(&mut bytes);
+/// assert_eq!(&bytes, b"HELLO, WORLD! 123");
+/// ```
+#[inline]
+pub fn make_ascii_uppercase<const N: usize>(bytes: &mut [u8])
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    map_blocks::<N>(
+        bytes,
+        |block| flip_case(block, b'a', b'z'),
+        <[u8]>::make_ascii_uppercase,
+    );
+}
+
+/// Converts the ASCII letters in `bytes` to lower case in place, `N` bytes at a time.
+///
+/// Other bytes are unchanged, like `<[u8]>::make_ascii_lowercase`.
+///
+/// ```
+/// # use std_search::make_ascii_lowercase;
+/// let mut bytes = *b"Hello, World! 123";
+/// make_ascii_lowercase::<8>(&mut bytes);
+/// assert_eq!(&bytes, b"hello, world! 123");
+/// ```
+#[inline]
+pub fn make_ascii_lowercase<const N: usize>(bytes: &mut [u8])
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    map_blocks::<N>(
+        bytes,
+        |block| flip_case(block, b'A', b'Z'),
+        <[u8]>::make_ascii_lowercase,
+    );
+}
+
+/// Returns `true` if `a` and `b` are equal ignoring ASCII case, comparing `N` bytes at a time.
+///
+/// This is a vectorized `<[u8]>::eq_ignore_ascii_case`.
+///
+/// ```
+/// # use std_search::eq_ignore_ascii_case;
+/// assert!(eq_ignore_ascii_case::<16>(b"Content-Type", b"content-type"));
+/// assert!(!eq_ignore_ascii_case::<16>(b"Content-Type", b"content-length"));
+/// ```
+#[inline]
+#[must_use]
+pub fn eq_ignore_ascii_case<const N: usize>(a: &[u8], b: &[u8]) -> bool
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut a_chunks = a.chunks_exact(N);
+    let mut b_chunks = b.chunks_exact(N);
+    let equal = a_chunks.by_ref().zip(b_chunks.by_ref()).all(|(a, b)| {
+        let a = flip_case(Simd::from_slice(a), b'A', b'Z');
+        let b = flip_case(Simd::from_slice(b), b'A', b'Z');
+        a.simd_eq(b).all()
+    });
+    equal
+        && a_chunks
+            .remainder()
+            .eq_ignore_ascii_case(b_chunks.remainder())
+}
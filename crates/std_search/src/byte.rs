@@ -0,0 +1,148 @@
+use crate::mask::{first, last, SearchMask};
+use crate::simd::{LaneCount, Mask, Simd, SimdPartialEq, SupportedLaneCount};
+
+/// Returns the index of the first block lane matched by `matches`.
+///
+/// Haystacks shorter than `N` are searched with `scalar`. Otherwise the final
+/// partial block is searched by loading the last `N` bytes, which overlaps lanes
+/// that were already searched and are known not to match.
+#[inline]
+fn find_by<const N: usize>(
+    haystack: &[u8],
+    matches: impl Fn(Simd<u8, N>) -> Mask<i8, N>,
+    scalar: impl Fn(u8) -> bool,
+) -> Option<usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+    Mask<i8, N>: SearchMask,
+{
+    if haystack.len() < N {
+        return haystack.iter().position(|&byte| scalar(byte));
+    }
+
+    let mut offset = 0;
+    while offset + N <= haystack.len() {
+        let block = Simd::from_slice(&haystack[offset..]);
+        if let Some(index) = first(matches(block).match_bits()) {
+            return Some(offset + index);
+        }
+        offset += N;
+    }
+
+    if offset < haystack.len() {
+        let start = haystack.len() - N;
+        let block = Simd::from_slice(&haystack[start..]);
+        first(matches(block).match_bits()).map(|index| start + index)
+    } else {
+        None
+    }
+}
+
+/// Returns the index of the last block lane matched by `matches`.
+///
+/// This is the mirror image of `find_by`, walking from the end of the haystack.
+#[inline]
+fn rfind_by<const N: usize>(
+    haystack: &[u8],
+    matches: impl Fn(Simd<u8, N>) -> Mask<i8, N>,
+    scalar: impl Fn(u8) -> bool,
+) -> Option<usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+    Mask<i8, N>: SearchMask,
+{
+    if haystack.len() < N {
+        return haystack.iter().rposition(|&byte| scalar(byte));
+    }
+
+    let mut end = haystack.len();
+    while end >= N {
+        let block = Simd::from_slice(&haystack[end - N..]);
+        if let Some(index) = last(matches(block).match_bits()) {
+            return Some(end - N + index);
+        }
+        end -= N;
+    }
+
+    if end > 0 {
+        let block = Simd::from_slice(haystack);
+        last(matches(block).match_bits())
+    } else {
+        None
+    }
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack`,
+/// comparing `N` bytes at a time.
+///
+/// ```
+/// # use std_search::find_byte;
+/// assert_eq!(find_byte::<16>(b"portable simd, portable search", b's'), Some(9));
+/// assert_eq!(find_byte::<16>(b"portable simd", b'z'), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn find_byte<const N: usize>(haystack: &[u8], needle: u8) -> Option<usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+    Mask<i8, N>: SearchMask,
+{
+    let needles = Simd::splat(needle);
+    find_by(
+        haystack,
+        |block| block.simd_eq(needles),
+        |byte| byte == needle,
+    )
+}
+
+/// Returns the index of the last occurrence of `needle` in `haystack`,
+/// comparing `N` bytes at a time.
+///
+/// ```
+/// # use std_search::rfind_byte;
+/// assert_eq!(rfind_byte::<16>(b"portable simd, portable search", b's'), Some(24));
+/// assert_eq!(rfind_byte::<16>(b"portable simd", b'z'), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn rfind_byte<const N: usize>(haystack: &[u8], needle: u8) -> Option<usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+    Mask<i8, N>: SearchMask,
+{
+    let needles = Simd::splat(needle);
+    rfind_by(
+        haystack,
+        |block| block.simd_eq(needles),
+        |byte| byte == needle,
+    )
+}
+
+/// Returns the index of the first byte in `haystack` that is equal to any of
+/// the bytes in `needles`, comparing `N` bytes at a time.
+///
+/// Each block is compared against every needle, so this is best suited to a
+/// small set of needles.
+///
+/// ```
+/// # use std_search::find_any_of;
+/// assert_eq!(find_any_of::<16>(b"key = value; next", b";="), Some(4));
+/// assert_eq!(find_any_of::<16>(b"key = value", b""), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn find_any_of<const N: usize>(haystack: &[u8], needles: &[u8]) -> Option<usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+    Mask<i8, N>: SearchMask,
+{
+    find_by(
+        haystack,
+        |block| {
+            needles.iter().fold(Mask::splat(false), |matched, &needle| {
+                matched | block.simd_eq(Simd::splat(needle))
+            })
+        },
+        |byte| needles.contains(&byte),
+    )
+}
@@ -0,0 +1,29 @@
+#![no_std]
+#![feature(portable_simd)]
+//! Byte and substring search, UTF-8 validation and ASCII operations over slices, built on
+//! portable SIMD.
+//!
+//! Every function is generic over the number of bytes `N` processed at once, each block of
+//! the input being loaded into a [`Simd<u8, N>`](simd::Simd).
+//!
+//! The searches compare each block lanewise against the needle, and turn the resulting mask
+//! into a bitmask with [`ToBitMask`](simd::ToBitMask) so that the position of a match can be
+//! read off with `trailing_zeros` or `leading_zeros`. UTF-8 validation looks up the nibbles of
+//! each byte in small tables with [`Simd::swizzle_dyn`](simd::Simd::swizzle_dyn).
+use core_simd::simd;
+
+mod ascii;
+mod byte;
+mod mask;
+mod memmem;
+mod utf8;
+
+pub use ascii::*;
+pub use byte::*;
+pub use mask::SearchMask;
+pub use memmem::*;
+pub use utf8::*;
+
+mod sealed {
+    pub trait Sealed {}
+}
@@ -0,0 +1,55 @@
+use crate::sealed::Sealed;
+use crate::simd::{LaneCount, Mask, SupportedLaneCount, ToBitMask};
+
+/// A mask over byte lanes that can report which of its lanes are set.
+///
+/// This is implemented for `Mask<i8, N>` for every lane count up to 64, which is
+/// what bounds the lane counts accepted by the search functions.
+pub trait SearchMask: Sealed + Copy {
+    /// Returns an integer with bit `i` set if lane `i` of the mask is set.
+    fn match_bits(self) -> u64;
+}
+
+impl<const N: usize> Sealed for Mask<i8, N> where LaneCount<N>: SupportedLaneCount {}
+
+impl<const N: usize> SearchMask for Mask<i8, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+    Self: ToBitMask,
+    <Self as ToBitMask>::BitMask: Into<u64>,
+{
+    #[inline]
+    fn match_bits(self) -> u64 {
+        self.to_bitmask().into()
+    }
+}
+
+/// Iterates over the indices of the set bits of a bitmask, lowest first.
+pub(crate) struct Matches(pub(crate) u64);
+
+impl Iterator for Matches {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.0 == 0 {
+            None
+        } else {
+            let index = self.0.trailing_zeros() as usize;
+            self.0 &= self.0 - 1;
+            Some(index)
+        }
+    }
+}
+
+/// Returns the index of the lowest set bit, if any.
+#[inline]
+pub(crate) fn first(bits: u64) -> Option<usize> {
+    (bits != 0).then(|| bits.trailing_zeros() as usize)
+}
+
+/// Returns the index of the highest set bit, if any.
+#[inline]
+pub(crate) fn last(bits: u64) -> Option<usize> {
+    (bits != 0).then(|| 63 - bits.leading_zeros() as usize)
+}
@@ -0,0 +1,67 @@
+use crate::byte::find_byte;
+use crate::mask::{Matches, SearchMask};
+use crate::simd::{LaneCount, Mask, Simd, SimdPartialEq, SupportedLaneCount};
+
+/// Returns the index of the first occurrence of `needle` in `haystack`,
+/// comparing `N` candidate positions at a time.
+///
+/// Candidates are found by comparing the first and last bytes of the needle
+/// against two blocks of the haystack, offset by the length of the needle.
+/// Only positions where both bytes match are compared in full.
+///
+/// An empty needle matches at index 0, like [`str::find`].
+///
+/// ```
+/// # use std_search::memmem;
+/// assert_eq!(memmem::<16>(b"portable simd, portable search", b"search"), Some(24));
+/// assert_eq!(memmem::<16>(b"portable simd", b"sims"), None);
+/// assert_eq!(memmem::<16>(b"portable simd", b""), Some(0));
+/// ```
+#[inline]
+#[must_use]
+pub fn memmem<const N: usize>(haystack: &[u8], needle: &[u8]) -> Option<usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+    Mask<i8, N>: SearchMask,
+{
+    let len = needle.len();
+    match len {
+        0 => return Some(0),
+        1 => return find_byte::<N>(haystack, needle[0]),
+        _ if len > haystack.len() => return None,
+        _ => {}
+    }
+
+    // Both blocks must fit in the haystack
+    if haystack.len() < len - 1 + N {
+        return haystack.windows(len).position(|window| window == needle);
+    }
+
+    let head = Simd::<u8, N>::splat(needle[0]);
+    let tail = Simd::<u8, N>::splat(needle[len - 1]);
+    let inner = &needle[1..len - 1];
+    let search = |start: usize, skip: usize| {
+        let first = Simd::from_slice(&haystack[start..]);
+        let last = Simd::from_slice(&haystack[start + len - 1..]);
+        let candidates = (first.simd_eq(head) & last.simd_eq(tail)).match_bits();
+        Matches(candidates)
+            .map(|index| start + index)
+            .filter(|&position| position >= skip)
+            .find(|&position| &haystack[position + 1..position + len - 1] == inner)
+    };
+
+    let mut offset = 0;
+    while offset + len - 1 + N <= haystack.len() {
+        if let Some(position) = search(offset, 0) {
+            return Some(position);
+        }
+        offset += N;
+    }
+
+    // Search the remaining positions with a final, overlapping pair of blocks
+    if offset + len <= haystack.len() {
+        search(haystack.len() - len + 1 - N, offset)
+    } else {
+        None
+    }
+}
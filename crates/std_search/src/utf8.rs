@@ -0,0 +1,245 @@
+use crate::simd::{LaneCount, Mask, Simd, SimdPartialEq, SimdUint, SupportedLaneCount};
+use core::str::Utf8Error;
+
+// Each lookup table maps a nibble to the set of errors it could take part in. A pair of bytes
+// is invalid when all three nibbles (the high and low nibbles of the first byte, and the high
+// nibble of the second) agree on at least one error.
+//
+// This is the "lookup" algorithm of John Keiser and Daniel Lemire, "Validating UTF-8 In Less
+// Than One Instruction Per Byte" (2021).
+
+/// A lead byte followed by another lead byte or by ASCII.
+const TOO_SHORT: u8 = 1 << 0;
+/// ASCII followed by a continuation byte.
+const TOO_LONG: u8 = 1 << 1;
+/// `E0` followed by `80..=9F`.
+const OVERLONG_3: u8 = 1 << 2;
+/// `F4` followed by `90..=BF`, or a lead byte of `F5` or above.
+const TOO_LARGE: u8 = 1 << 3;
+/// `ED` followed by `A0..=BF`.
+const SURROGATE: u8 = 1 << 4;
+/// `C0` or `C1` followed by a continuation byte.
+const OVERLONG_2: u8 = 1 << 5;
+/// A lead byte of `F5` or above followed by `80..=8F`.
+const TOO_LARGE_1000: u8 = 1 << 6;
+/// `F0` followed by `80..=8F`.
+const OVERLONG_4: u8 = 1 << 6;
+/// Two continuation bytes in a row, which is only valid in three and four byte sequences.
+const TWO_CONTS: u8 = 1 << 7;
+/// The errors that apply whatever the low nibble of the first byte.
+const CARRY: u8 = TOO_SHORT | TOO_LONG | TWO_CONTS;
+
+const BYTE_1_HIGH: Simd<u8, 16> = Simd::from_array([
+    // 0_______ ASCII
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    TOO_LONG,
+    // 10______ continuation
+    TWO_CONTS,
+    TWO_CONTS,
+    TWO_CONTS,
+    TWO_CONTS,
+    // 1100____ two byte lead
+    TOO_SHORT | OVERLONG_2,
+    // 1101____ two byte lead
+    TOO_SHORT,
+    // 1110____ three byte lead
+    TOO_SHORT | OVERLONG_3 | SURROGATE,
+    // 1111____ four byte lead
+    TOO_SHORT | TOO_LARGE | TOO_LARGE_1000 | OVERLONG_4,
+]);
+
+const BYTE_1_LOW: Simd<u8, 16> = Simd::from_array([
+    // ____0000
+    CARRY | OVERLONG_3 | OVERLONG_2 | OVERLONG_4,
+    // ____0001
+    CARRY | OVERLONG_2,
+    // ____001_
+    CARRY,
+    CARRY,
+    // ____0100
+    CARRY | TOO_LARGE,
+    // ____0101
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    // ____011_
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    // ____1___
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    // ____1101
+    CARRY | TOO_LARGE | TOO_LARGE_1000 | SURROGATE,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+    CARRY | TOO_LARGE | TOO_LARGE_1000,
+]);
+
+const BYTE_2_HIGH: Simd<u8, 16> = Simd::from_array([
+    // 0_______ ASCII
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    // 1000____
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | OVERLONG_3 | TOO_LARGE_1000 | OVERLONG_4,
+    // 1001____
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | OVERLONG_3 | TOO_LARGE,
+    // 101_____
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | SURROGATE | TOO_LARGE,
+    TOO_LONG | OVERLONG_2 | TWO_CONTS | SURROGATE | TOO_LARGE,
+    // 11______ lead
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+    TOO_SHORT,
+]);
+
+/// Returns `input` shifted up by `K` lanes, with the last `K` lanes of `prev` shifted in.
+#[inline]
+fn prev<const K: usize, const N: usize>(input: Simd<u8, N>, prev: Simd<u8, N>) -> Simd<u8, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    Mask::<i8, N>::from_array(core::array::from_fn(|i| i < K)).select(
+        prev.rotate_lanes_right::<K>(),
+        input.rotate_lanes_right::<K>(),
+    )
+}
+
+/// Validates UTF-8 one block at a time, carrying the end of each block into the next.
+struct Utf8Checker<const N: usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Nonzero in any lane where an error was found.
+    error: Simd<u8, N>,
+    prev_input: Simd<u8, N>,
+    /// Nonzero if the previous block ends with an incomplete sequence.
+    prev_incomplete: Simd<u8, N>,
+}
+
+impl<const N: usize> Utf8Checker<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn new() -> Self {
+        Self {
+            error: Simd::splat(0),
+            prev_input: Simd::splat(0),
+            prev_incomplete: Simd::splat(0),
+        }
+    }
+
+    #[inline]
+    fn check_block(&mut self, input: Simd<u8, N>) {
+        if (input & Simd::splat(0x80)).simd_eq(Simd::splat(0)).all() {
+            // An ASCII block is only invalid if the previous block was incomplete
+            self.error |= self.prev_incomplete;
+            self.prev_incomplete = Simd::splat(0);
+        } else {
+            let prev1 = prev::<1, N>(input, self.prev_input);
+            let special_cases = BYTE_1_HIGH.swizzle_dyn_table(prev1 >> Simd::splat(4))
+                & BYTE_1_LOW.swizzle_dyn_table(prev1 & Simd::splat(0x0f))
+                & BYTE_2_HIGH.swizzle_dyn_table(input >> Simd::splat(4));
+
+            // The bytes after a three or four byte lead must be continuations, which is the
+            // only place `TWO_CONTS` is allowed
+            let prev2 = prev::<2, N>(input, self.prev_input);
+            let prev3 = prev::<3, N>(input, self.prev_input);
+            let must_be_continuation = (prev2.saturating_sub(Simd::splat(0xe0 - 0x80))
+                | prev3.saturating_sub(Simd::splat(0xf0 - 0x80)))
+                & Simd::splat(0x80);
+            self.error |= must_be_continuation ^ special_cases;
+
+            // Only the last three bytes can start a sequence that continues into the next block
+            let max = Simd::from_array(core::array::from_fn(|i| match N - i {
+                1 => 0b11000000 - 1,
+                2 => 0b11100000 - 1,
+                3 => 0b11110000 - 1,
+                _ => u8::MAX,
+            }));
+            self.prev_incomplete = input.saturating_sub(max);
+        }
+        self.prev_input = input;
+    }
+
+    #[inline]
+    fn is_valid(&self) -> bool {
+        (self.error | self.prev_incomplete)
+            .simd_eq(Simd::splat(0))
+            .all()
+    }
+}
+
+/// Returns `true` if `bytes` is valid UTF-8, checking `N` bytes at a time.
+///
+/// `N` must be at least 4, so that each block holds the end of the sequence before it. Smaller
+/// lane counts fall back to [`str::from_utf8`].
+///
+/// ```
+/// # use std_search::is_utf8;
+/// assert!(is_utf8::<16>("grüße, 世界 🦀".as_bytes()));
+/// assert!(!is_utf8::<16>(b"surrogate \xed\xa0\x80"));
+/// assert!(!is_utf8::<16>(b"truncated \xe4\xb8"));
+/// ```
+#[inline]
+#[must_use]
+pub fn is_utf8<const N: usize>(bytes: &[u8]) -> bool
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    if N < 4 {
+        return core::str::from_utf8(bytes).is_ok();
+    }
+
+    let mut checker = Utf8Checker::<N>::new();
+    let mut chunks = bytes.chunks_exact(N);
+    for chunk in &mut chunks {
+        checker.check_block(Simd::from_slice(chunk));
+    }
+
+    // Pad the last block with ASCII, which ends any incomplete sequence with an error
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        let mut last = [0; N];
+        last[..remainder.len()].copy_from_slice(remainder);
+        checker.check_block(Simd::from_array(last));
+    }
+    checker.is_valid()
+}
+
+/// Converts a slice of bytes to a string slice, checking `N` bytes at a time.
+///
+/// This is a vectorized [`str::from_utf8`]. The errors are the same, as invalid input is
+/// revalidated by [`str::from_utf8`] to locate the error.
+///
+/// ```
+/// # use std_search::from_utf8;
+/// assert_eq!(from_utf8::<16>("grüße, 世界 🦀".as_bytes()), Ok("grüße, 世界 🦀"));
+/// let error = from_utf8::<16>(b"valid until \xff").unwrap_err();
+/// assert_eq!(error.valid_up_to(), 12);
+/// ```
+#[inline]
+pub fn from_utf8<const N: usize>(bytes: &[u8]) -> Result<&str, Utf8Error>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    if is_utf8::<N>(bytes) {
+        // Safety: the bytes are valid UTF-8
+        Ok(unsafe { core::str::from_utf8_unchecked(bytes) })
+    } else {
+        core::str::from_utf8(bytes)
+    }
+}
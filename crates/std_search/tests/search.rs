@@ -0,0 +1,91 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::Mask;
+use std_search::{find_any_of, find_byte, memmem, rfind_byte, SearchMask};
+
+/// Maps arbitrary bytes onto a small alphabet, so that matches are common.
+fn alphabet<const N: usize>(bytes: [u8; N]) -> [u8; N] {
+    bytes.map(|byte| b'a' + byte % 4)
+}
+
+test_helpers::test_lanes! {
+    fn find_byte_matches_scalar<const LANES: usize>()
+    where
+        Mask<i8, LANES>: SearchMask,
+    {
+        test_helpers::test_2(&|haystack: [u8; 160], needle: u8| {
+            let haystack = alphabet(haystack);
+            let needle = b'a' + needle % 5;
+            for len in 0..=haystack.len() {
+                let haystack = &haystack[..len];
+                proptest::prop_assert_eq!(
+                    find_byte::<LANES>(haystack, needle),
+                    haystack.iter().position(|&byte| byte == needle)
+                );
+                proptest::prop_assert_eq!(
+                    rfind_byte::<LANES>(haystack, needle),
+                    haystack.iter().rposition(|&byte| byte == needle)
+                );
+            }
+            Ok(())
+        });
+    }
+
+    fn find_any_of_matches_scalar<const LANES: usize>()
+    where
+        Mask<i8, LANES>: SearchMask,
+    {
+        test_helpers::test_3(&|haystack: [u8; 160], needles: [u8; 3], count: u8| {
+            let haystack = alphabet(haystack);
+            let needles = &needles.map(|byte| b'b' + byte % 8)[..usize::from(count % 4)];
+            for len in 0..=haystack.len() {
+                let haystack = &haystack[..len];
+                proptest::prop_assert_eq!(
+                    find_any_of::<LANES>(haystack, needles),
+                    haystack.iter().position(|byte| needles.contains(byte))
+                );
+            }
+            Ok(())
+        });
+    }
+
+    fn memmem_matches_scalar<const LANES: usize>()
+    where
+        Mask<i8, LANES>: SearchMask,
+    {
+        test_helpers::test_3(&|haystack: [u8; 160], needle: [u8; 5], count: u8| {
+            let haystack = alphabet(haystack);
+            let needle = alphabet(needle);
+            let needle = &needle[..usize::from(count % 6)];
+            for len in 0..=haystack.len() {
+                let haystack = &haystack[..len];
+                let expected = if needle.is_empty() {
+                    Some(0)
+                } else {
+                    haystack.windows(needle.len()).position(|window| window == needle)
+                };
+                proptest::prop_assert_eq!(memmem::<LANES>(haystack, needle), expected);
+            }
+            Ok(())
+        });
+    }
+
+    fn memmem_long_needle<const LANES: usize>()
+    where
+        Mask<i8, LANES>: SearchMask,
+    {
+        let haystack: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+        for start in [0, 1, 250, 251, 500, 900] {
+            let needle = &haystack[start..start + 100];
+            assert_eq!(memmem::<LANES>(&haystack, needle), Some(start % 251));
+        }
+        assert_eq!(memmem::<LANES>(&haystack[1..], &haystack[..100]), Some(250));
+        assert_eq!(memmem::<LANES>(&haystack[..99], &haystack[..100]), None);
+    }
+}
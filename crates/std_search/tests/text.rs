@@ -0,0 +1,149 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use std_search::{
+    eq_ignore_ascii_case, from_utf8, is_ascii, is_utf8, make_ascii_lowercase, make_ascii_uppercase,
+};
+
+/// Fragments of UTF-8, both valid and invalid, to build test strings from.
+const FRAGMENTS: &[&[u8]] = &[
+    b"a",
+    b"Z",
+    b" ",
+    b"~",
+    "ß".as_bytes(),
+    "\u{7ff}".as_bytes(),
+    "世".as_bytes(),
+    "\u{ffff}".as_bytes(),
+    "\u{d7ff}".as_bytes(),
+    "\u{e000}".as_bytes(),
+    "🦀".as_bytes(),
+    "\u{10000}".as_bytes(),
+    "\u{10ffff}".as_bytes(),
+    b"ok",
+    b"valid",
+    b"ASCII",
+    // Invalid
+    b"\x80",
+    b"\xbf",
+    b"\xc0\x80",
+    b"\xc1\xbf",
+    b"\xe0\x80\x80",
+    b"\xe0\x9f\xbf",
+    b"\xed\xa0\x80",
+    b"\xed\xbf\xbf",
+    b"\xf0\x8f\xbf\xbf",
+    b"\xf4\x90\x80\x80",
+    b"\xf5\x80\x80\x80",
+    b"\xff",
+    b"\xc3",
+    b"\xe4\xb8",
+    b"\xf0\x9f\xa6",
+    b"\xc3\x9f\x9f",
+];
+
+/// Builds a string of fragments, mostly valid ones.
+fn fragments<const N: usize>(choices: [u8; N]) -> Vec<u8> {
+    choices
+        .iter()
+        .flat_map(|&choice| {
+            let choice = if choice < 224 {
+                usize::from(choice) % 16
+            } else {
+                usize::from(choice) % FRAGMENTS.len()
+            };
+            FRAGMENTS[choice].iter().copied()
+        })
+        .collect()
+}
+
+test_helpers::test_lanes! {
+    fn utf8_matches_scalar<const LANES: usize>() {
+        test_helpers::test_1(&|choices: [u8; 24]| {
+            let bytes = fragments(choices);
+            for len in 0..=bytes.len() {
+                let bytes = &bytes[..len];
+                proptest::prop_assert_eq!(
+                    is_utf8::<LANES>(bytes),
+                    core::str::from_utf8(bytes).is_ok()
+                );
+                proptest::prop_assert_eq!(from_utf8::<LANES>(bytes), core::str::from_utf8(bytes));
+            }
+            Ok(())
+        });
+    }
+
+    fn utf8_arbitrary_bytes<const LANES: usize>() {
+        test_helpers::test_1(&|bytes: [u8; 100]| {
+            proptest::prop_assert_eq!(
+                is_utf8::<LANES>(&bytes),
+                core::str::from_utf8(&bytes).is_ok()
+            );
+            Ok(())
+        });
+    }
+
+    fn utf8_pairs_across_blocks<const LANES: usize>() {
+        // Every pair of bytes, straddling the boundary between two blocks
+        let mut bytes = vec![b'a'; 2 * LANES + 1];
+        for pair in 0..=u16::MAX {
+            bytes[LANES - 1..LANES + 1].copy_from_slice(&pair.to_be_bytes());
+            assert_eq!(
+                is_utf8::<LANES>(&bytes),
+                core::str::from_utf8(&bytes).is_ok(),
+                "{:02x?}",
+                pair.to_be_bytes()
+            );
+        }
+    }
+
+    fn ascii_matches_scalar<const LANES: usize>() {
+        test_helpers::test_1(&|bytes: [u8; 100]| {
+            // Mostly ASCII, so that `is_ascii` is often true
+            let bytes = bytes.map(|byte| if byte < 240 { byte & 0x7f } else { byte });
+            for len in 0..=bytes.len() {
+                let bytes = &bytes[..len];
+                proptest::prop_assert_eq!(is_ascii::<LANES>(bytes), bytes.is_ascii());
+
+                let mut upper = bytes.to_vec();
+                make_ascii_uppercase::<LANES>(&mut upper);
+                proptest::prop_assert_eq!(&upper, &bytes.to_ascii_uppercase());
+
+                let mut lower = bytes.to_vec();
+                make_ascii_lowercase::<LANES>(&mut lower);
+                proptest::prop_assert_eq!(&lower, &bytes.to_ascii_lowercase());
+            }
+            Ok(())
+        });
+    }
+
+    fn eq_ignore_ascii_case_matches_scalar<const LANES: usize>() {
+        test_helpers::test_2(&|a: [u8; 100], flips: [u8; 100]| {
+            // Compare against a copy with some letters changed in case, and some other bytes flipped
+            let mut b = a;
+            for (b, flip) in b.iter_mut().zip(flips) {
+                if flip < 128 {
+                    *b ^= 0x20;
+                }
+                if flip == 255 {
+                    *b ^= 0x01;
+                }
+            }
+            for len in 0..=a.len() {
+                let (a, b) = (&a[..len], &b[..len]);
+                proptest::prop_assert_eq!(
+                    eq_ignore_ascii_case::<LANES>(a, b),
+                    a.eq_ignore_ascii_case(b)
+                );
+                proptest::prop_assert!(eq_ignore_ascii_case::<LANES>(a, &a.to_ascii_uppercase()));
+            }
+            proptest::prop_assert!(!eq_ignore_ascii_case::<LANES>(&a, &a[1..]));
+            Ok(())
+        });
+    }
+}
@@ -391,10 +391,16 @@ macro_rules! test_lanes_helper {
 }
 
 /// Expand a const-generic test into separate tests for each possible lane count.
+///
+/// The test may have a `where` clause, for bounds beyond the supported lane count.
 #[macro_export]
 macro_rules! test_lanes {
     {
-        $(fn $test:ident<const $lanes:ident: usize>() $body:tt)*
+        $(
+            fn $test:ident<const $lanes:ident: usize>()
+            $(where $($bounded:ty: $bound:path),+ $(,)?)?
+            $body:block
+        )*
     } => {
         $(
             mod $test {
@@ -403,6 +409,7 @@ macro_rules! test_lanes {
                 fn implementation<const $lanes: usize>()
                 where
                     core_simd::simd::LaneCount<$lanes>: core_simd::simd::SupportedLaneCount,
+                    $($($bounded: $bound,)+)?
                 $body
 
                 #[cfg(target_arch = "wasm32")]